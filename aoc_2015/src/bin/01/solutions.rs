@@ -1,3 +1,4 @@
+use anyhow::{anyhow, Result};
 use aocr_macro::aoc;
 
 #[aoc(part = "One")]
@@ -6,7 +7,7 @@ pub fn solve_one(input: &str) -> i64 {
 }
 
 #[aoc(part = "Two", name = "closures")]
-pub fn solve_two(input: &str) -> i64 {
+pub fn solve_two(input: &str) -> Result<i64> {
     input
         .chars()
         .map(from)
@@ -17,19 +18,19 @@ pub fn solve_two(input: &str) -> i64 {
         })
         .find(|&(count, _)| count < 0)
         .map(|(_, i)| i as i64 + 1)
-        .expect("Santa didn't reach the basement")
+        .ok_or_else(|| anyhow!("Santa didn't reach the basement"))
 }
 
 #[aoc(part = "Two", name = "for_loop")]
-pub fn solve_two_for_loop(input: &str) -> i64 {
+pub fn solve_two_for_loop(input: &str) -> Result<i64> {
     let mut count = 0;
     for (i, c) in input.chars().enumerate() {
         count += from(c);
         if count < 0 {
-            return i as i64 + 1;
+            return Ok(i as i64 + 1);
         }
     }
-    panic!("Santa didn't reach the basement")
+    Err(anyhow!("Santa didn't reach the basement"))
 }
 
 fn from(ch: char) -> i64 {
@@ -85,17 +86,17 @@ mod tests {
     #[case("())", 3)]
     #[case("(())())", 7)]
     fn test_part_two(#[case] input: &str, #[case] expected: i64) {
-        assert_eq!(solve_two(input), expected);
-        assert_eq!(solve_two_for_loop(input), expected);
+        assert_eq!(solve_two(input).unwrap(), expected);
+        assert_eq!(solve_two_for_loop(input).unwrap(), expected);
     }
 
     #[rstest]
     #[case("(())")]
     #[case("(((")]
     #[case("(()(()(")]
-    #[should_panic(expected = "Santa didn't reach the basement")]
-    fn test_part_two_panic(#[case] input: &str) {
-        solve_two(input);
-        solve_two_for_loop(input);
+    fn test_part_two_error(#[case] input: &str) {
+        let expected = "Santa didn't reach the basement";
+        assert_eq!(solve_two(input).unwrap_err().to_string(), expected);
+        assert_eq!(solve_two_for_loop(input).unwrap_err().to_string(), expected);
     }
 }
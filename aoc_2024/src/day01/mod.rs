@@ -0,0 +1,3 @@
+mod part_one;
+
+aocr_macro::aoc_examples!("inputs/2024/01.txt");
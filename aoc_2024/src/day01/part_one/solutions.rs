@@ -22,14 +22,3 @@ pub fn solve_one(input: &str) -> i64 {
 fn parse_tuple(s: (&str, &str)) -> (i64, i64) {
     (s.0.parse().unwrap(), s.1.parse().unwrap())
 }
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_part_one_input() {
-        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
-
-        let expected = 11;
-        pretty_assertions::assert_eq!(super::solve_one(input), expected);
-    }
-}
@@ -0,0 +1,3 @@
+mod day01;
+
+aocr::main!(2024);
@@ -0,0 +1,150 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miette::{miette, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{Answer, Part};
+
+/// The answers of a day, stored next to its input.
+///
+/// For `inputs/2024/01.txt` the answers live in `inputs/2024/01.answers.toml`, holding the
+/// accepted answers along with the last ones computed, one entry per part:
+///
+/// ```toml
+/// [accepted]
+/// one = "11"
+///
+/// [last]
+/// one = "11"
+/// two = "31"
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AnswersFile {
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(default)]
+    accepted: BTreeMap<String, String>,
+    #[serde(default)]
+    last: BTreeMap<String, String>,
+}
+
+/// The outcome of checking an answer against the accepted one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verification {
+    /// The answer matches the accepted answer.
+    Correct,
+    /// The answer differs from the accepted answer.
+    Incorrect { expected: Answer },
+    /// No answer has been accepted for the part yet.
+    Unknown,
+}
+
+impl AnswersFile {
+    /// Loads the answers recorded for the given input, or an empty set if there are none.
+    pub fn load(input_path: &Path) -> Result<Self> {
+        let path = Self::path_for(input_path);
+        let answers = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| miette!("Failed to parse {}: {}", path.display(), e))?,
+            Err(_) => Self::default(),
+        };
+
+        Ok(AnswersFile { path, ..answers })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let contents = toml::to_string(self).map_err(|e| miette!("{}", e))?;
+        fs::write(&self.path, contents)
+            .map_err(|e| miette!("Failed to write {}: {:?}", self.path.display(), e))
+    }
+
+    pub fn get(&self, part: &Part) -> Option<Answer> {
+        self.accepted.get(part.as_str()).map(Answer::new)
+    }
+
+    pub fn record(&mut self, part: &Part, answer: &Answer) {
+        self.accepted
+            .insert(part.as_str().to_string(), answer.to_string());
+    }
+
+    /// Whether no answer has been accepted for any part.
+    pub fn is_empty(&self) -> bool {
+        self.accepted.is_empty()
+    }
+
+    /// The answer computed by the last run of the part.
+    pub fn last(&self, part: &Part) -> Option<Answer> {
+        self.last.get(part.as_str()).map(Answer::new)
+    }
+
+    pub fn record_last(&mut self, part: &Part, answer: &Answer) {
+        self.last
+            .insert(part.as_str().to_string(), answer.to_string());
+    }
+
+    pub fn verify(&self, part: &Part, answer: &Answer) -> Verification {
+        match self.get(part) {
+            Some(expected) if expected == *answer => Verification::Correct,
+            Some(expected) => Verification::Incorrect { expected },
+            None => Verification::Unknown,
+        }
+    }
+
+    fn path_for(input_path: &Path) -> PathBuf {
+        input_path.with_extension("answers.toml")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_path_for() {
+        let path = AnswersFile::path_for(Path::new("inputs/2024/01.txt"));
+        assert_eq!(path, PathBuf::from("inputs/2024/01.answers.toml"));
+    }
+
+    #[test]
+    fn test_record_and_verify() {
+        let input_path = PathBuf::from("test_answers_input.txt");
+
+        let mut answers = AnswersFile::load(&input_path).unwrap();
+        assert!(answers.is_empty());
+        assert_eq!(
+            answers.verify(&Part::One, &Answer::new(11)),
+            Verification::Unknown
+        );
+
+        answers.record(&Part::One, &Answer::new(11));
+        answers.record_last(&Part::Two, &Answer::new(31));
+        answers.save().unwrap();
+
+        let answers = AnswersFile::load(&input_path).unwrap();
+        assert_eq!(answers.get(&Part::One), Some(Answer::new(11)));
+        assert_eq!(answers.last(&Part::Two), Some(Answer::new(31)));
+        assert_eq!(answers.get(&Part::Two), None);
+        assert_eq!(
+            answers.verify(&Part::One, &Answer::new(11)),
+            Verification::Correct
+        );
+        assert_eq!(
+            answers.verify(&Part::One, &Answer::new(12)),
+            Verification::Incorrect {
+                expected: Answer::new(11)
+            }
+        );
+        assert_eq!(
+            answers.verify(&Part::Two, &Answer::new(11)),
+            Verification::Unknown
+        );
+
+        // Cleanup
+        std::fs::remove_file("test_answers_input.answers.toml").unwrap();
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miette::{miette, Result};
+
+use crate::runner::{Answer, Part};
+
+/// An example from the puzzle text, stored next to the input of its day.
+///
+/// For `inputs/2024/01.txt` the examples live in `inputs/2024/01.example1.txt`,
+/// `inputs/2024/01.example2.txt` and so on. The expected answers go in a front matter header,
+/// one entry per part, and can be left out for parts the example doesn't cover:
+///
+/// ```text
+/// ---
+/// one = 11
+/// two = 31
+/// ---
+/// 3   4
+/// 4   3
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    path: PathBuf,
+    input: String,
+    expected: HashMap<Part, Answer>,
+}
+
+impl Example {
+    pub fn new(path: PathBuf, input: impl Into<String>) -> Self {
+        Example {
+            path,
+            input: input.into(),
+            expected: HashMap::new(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| miette!("Failed to read example {}: {:?}", path.display(), e))?;
+        Self::parse(path, &contents)
+    }
+
+    pub fn parse(path: &Path, contents: &str) -> Result<Self> {
+        let (header, input) = match contents.strip_prefix("---\n") {
+            Some(rest) => match rest.split_once("\n---\n") {
+                Some((header, input)) => (header, input),
+                None => rest
+                    .strip_suffix("\n---")
+                    .map(|header| (header, ""))
+                    .ok_or_else(|| miette!("Unterminated header in {}", path.display()))?,
+            },
+            None => ("", contents),
+        };
+
+        let header: BTreeMap<String, toml::Value> = toml::from_str(header)
+            .map_err(|e| miette!("Failed to parse the header of {}: {}", path.display(), e))?;
+        let expected = header
+            .into_iter()
+            .map(|(part, answer)| {
+                let answer = match answer {
+                    toml::Value::String(answer) => Answer::new(answer),
+                    answer => Answer::new(answer),
+                };
+                Ok((part.parse()?, answer))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Example {
+            path: path.to_path_buf(),
+            input: input.to_string(),
+            expected,
+        })
+    }
+
+    /// Finds the examples of the given input, sorted by their number.
+    pub fn find(input_path: &Path) -> Result<Vec<PathBuf>> {
+        let dir = match input_path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let Some(prefix) = input_path
+            .file_stem()
+            .map(|stem| format!("{}.example", stem.to_string_lossy()))
+        else {
+            return Ok(vec![]);
+        };
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Ok(vec![]);
+        };
+
+        let mut examples = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter_map(|path| {
+                let name = path.file_name()?.to_str()?;
+                let number = name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+                Some((number.parse::<u32>().ok()?, path))
+            })
+            .collect::<Vec<_>>();
+        examples.sort();
+
+        Ok(examples.into_iter().map(|(_, path)| path).collect())
+    }
+
+    /// The name of the example, from its file name (`01.example1.txt` is `example1`).
+    pub fn name(&self) -> &str {
+        self.path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.rsplit('.').next())
+            .unwrap_or("example")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn expected(&self, part: &Part) -> Option<&Answer> {
+        self.expected.get(part)
+    }
+
+    pub fn set_expected(&mut self, part: Part, answer: Answer) {
+        self.expected.insert(part, answer);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.to_contents()?)
+            .map_err(|e| miette!("Failed to write {}: {:?}", self.path.display(), e))
+    }
+
+    /// Renders the example back into its file format, with numeric answers written as numbers.
+    fn to_contents(&self) -> Result<String> {
+        let header = self
+            .expected
+            .iter()
+            .map(|(part, answer)| {
+                let answer = match answer.as_str().parse::<i64>() {
+                    Ok(answer) => toml::Value::Integer(answer),
+                    Err(_) => toml::Value::String(answer.to_string()),
+                };
+                (part.as_str(), answer)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let header = toml::to_string(&header).map_err(|e| miette!("{}", e))?;
+
+        Ok(format!("---\n{}---\n{}", header, self.input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_with_header() {
+        let path = Path::new("inputs/2024/01.example1.txt");
+        let example =
+            Example::parse(path, "---\none = 11\ntwo = \"31\"\n---\n3   4\n4   3\n").unwrap();
+
+        assert_eq!(example.name(), "example1");
+        assert_eq!(example.input(), "3   4\n4   3\n");
+        assert_eq!(example.expected(&Part::One), Some(&Answer::new(11)));
+        assert_eq!(example.expected(&Part::Two), Some(&Answer::new(31)));
+    }
+
+    #[test]
+    fn test_parse_without_header() {
+        let path = Path::new("inputs/2024/01.example2.txt");
+        let example = Example::parse(path, "3   4\n").unwrap();
+
+        assert_eq!(example.input(), "3   4\n");
+        assert_eq!(example.expected(&Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_with_invalid_header() {
+        let path = Path::new("inputs/2024/01.example1.txt");
+
+        assert!(Example::parse(path, "---\nthree = 1\n---\n").is_err());
+        assert!(Example::parse(path, "---\none = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_to_contents_round_trip() {
+        let path = Path::new("inputs/2024/01.example1.txt");
+        let mut example = Example::new(path.to_path_buf(), "3   4\n4   3\n");
+        example.set_expected(Part::Two, Answer::new("ABC"));
+        example.set_expected(Part::One, Answer::new(11));
+
+        let contents = example.to_contents().unwrap();
+
+        assert_eq!(
+            contents,
+            "---\none = 11\ntwo = \"ABC\"\n---\n3   4\n4   3\n"
+        );
+        assert_eq!(Example::parse(path, &contents).unwrap(), example);
+    }
+
+    #[test]
+    fn test_find() {
+        let dir = PathBuf::from("test_examples");
+        fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "01.txt",
+            "01.example2.txt",
+            "01.example10.txt",
+            "01.example1.txt",
+            "02.example1.txt",
+        ] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let examples = Example::find(&dir.join("01.txt")).unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                dir.join("01.example1.txt"),
+                dir.join("01.example2.txt"),
+                dir.join("01.example10.txt"),
+            ]
+        );
+
+        // Cleanup
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,2 +1,3 @@
+pub mod answers;
+pub mod examples;
 pub mod runner;
-
@@ -1,28 +1,121 @@
 use clipboard::{ClipboardContext, ClipboardProvider};
 use colored::*;
-use std::{collections::HashMap, fmt::Display, path::PathBuf, str::FromStr, sync::RwLock};
+use std::{
+    any::{type_name, Any},
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
-use anyhow::{anyhow, Result};
 use clap::{Parser, ValueEnum};
+use miette::{miette, NamedSource, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::answers::AnswersFile;
+use report::Prepared;
+
+mod bench;
+mod examples;
+mod list;
+mod output;
+mod profiles;
+mod registry;
+mod report;
+pub mod table;
+mod variants;
+
+pub use examples::check_example;
+pub use list::{table as listing_table, Listing};
+pub use output::{OutputFormat, Record};
+pub use registry::{Generator, Registered, Registry, Solution, SolutionKey, GENERATORS, SOLUTIONS};
+pub use report::{PuzzleId, RunReport, SolutionReport, Status};
 
 /// Runner arguments for Advent of Code problems.
 ///
 /// This struct is used to parse command line arguments for the `aocr` binary.
-#[derive(Debug, Parser)]
+#[derive(Debug, Clone, Parser)]
 #[command(version)]
 pub struct AocRunnerArgs {
+    /// The day to run, for binaries built with every day of a year.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Runs every day of the year, for binaries built with every day of a year.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Reads the puzzle input from this file instead, or from stdin with `-`.
+    ///
+    /// Answers are checked and recorded next to the file, and not at all when reading stdin.
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Reads the input of the named profile, stored as `{day}/{profile}.txt` next to the input.
+    ///
+    /// Profiles hold the inputs of other accounts, each with its own recorded answers.
+    #[arg(long, conflicts_with = "input")]
+    profile: Option<String>,
+    /// Runs the solutions against the input of every profile, reporting the ones they get wrong.
+    #[arg(
+        long,
+        conflicts_with_all = ["input", "profile", "accept", "bench", "example", "all_variants"]
+    )]
+    all_profiles: bool,
     /// The part of the Advent of Code problem to run.
-    #[arg(short, long, default_value = "one")]
-    part: Part,
+    ///
+    /// Every registered part is run, in order, when omitted.
+    #[arg(short, long)]
+    part: Option<Part>,
     /// The optional name of the solution function.
     ///
-    /// This is helpful when running multiple solutions for the same problem.
-    #[arg(short, long, default_value = "solution")]
-    name: String,
+    /// This is helpful when running multiple solutions for the same problem. May be repeated to
+    /// run several of them.
+    #[arg(short, long = "name", default_value = "solution")]
+    names: Vec<String>,
+    /// Runs every named variant of the part and checks that they agree on the answer.
+    #[arg(long, conflicts_with = "names")]
+    all_variants: bool,
+    /// Records the answers as the accepted ones for this input.
+    ///
+    /// Later runs are checked against them, and fail when an answer no longer matches.
+    #[arg(long, conflicts_with_all = ["bench", "all_variants"])]
+    accept: bool,
+    /// Don't report how long parsing and solving took, for deterministic output.
+    #[arg(long)]
+    no_timing: bool,
+    /// Benchmark the selected solutions in-process instead of running them once.
+    #[arg(long)]
+    bench: bool,
+    /// Number of unmeasured iterations run before benchmarking each solution.
+    #[arg(long, default_value_t = 100, requires = "bench")]
+    warmup: u32,
+    /// Number of measured iterations per solution when benchmarking.
+    #[arg(long, default_value_t = 1000, requires = "bench", value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Writes the benchmark results as a Markdown table to the given file.
+    #[arg(long, requires = "bench")]
+    export_markdown: Option<PathBuf>,
+    /// Runs against the examples stored next to the input instead of the input itself.
+    ///
+    /// Every example with an expected answer for a part is checked against it.
+    #[arg(long, conflicts_with_all = ["bench", "accept"])]
+    example: bool,
+    /// How the results are reported, `plain` and `json` giving a line per solution run.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty, conflicts_with = "bench")]
+    output: OutputFormat,
+    /// Lists the registered solutions with where they are defined, instead of running them.
+    #[arg(
+        long,
+        conflicts_with_all = ["input", "profile", "all_profiles", "accept", "bench", "example", "all_variants"]
+    )]
+    list: bool,
 }
 
 /// Represents the part of the Advent of Code problem.
-#[derive(ValueEnum, Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(ValueEnum, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Part {
     /// Part one of the problem.
     One,
@@ -37,6 +130,14 @@ impl Part {
             Part::Two => "two",
         }
     }
+
+    /// The part as a number, as the Advent of Code website calls it a level.
+    pub fn level(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
 }
 
 impl Display for Part {
@@ -46,110 +147,689 @@ impl Display for Part {
 }
 
 impl FromStr for Part {
-    type Err = anyhow::Error;
+    type Err = miette::Report;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "1" | "one" => Ok(Part::One),
             "2" | "two" => Ok(Part::Two),
-            _ => Err(anyhow::anyhow!("Invalid part: {}", s)),
+            _ => Err(miette!("Invalid part: {}", s)),
         }
     }
 }
 
-impl From<&str> for Part {
-    fn from(s: &str) -> Self {
-        s.parse().unwrap()
+/// The answer produced by a solution function.
+///
+/// Any [`Display`] value can become an answer, so solutions are free to return integers of any
+/// width, `String`s or string slices.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn new(value: impl Display) -> Self {
+        Answer(value.to_string())
     }
-}
 
-impl From<String> for Part {
-    fn from(s: String) -> Self {
-        s.as_str().into()
+    pub fn as_str(&self) -> &str {
+        &self.0
     }
 }
 
-type SolutionFn = fn(&str) -> i64;
-type FunctionRegistry = HashMap<String, SolutionFn>;
-
-lazy_static::lazy_static! {
-    static ref FUNCTION_REGISTRY: RwLock<FunctionRegistry> = RwLock::new(HashMap::new());
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
 }
 
-/// Registers a solution function for the given part and name.
+/// The puzzle input handed to solution functions.
 ///
-/// Intended to be used by a proc macro to automatically register solution functions.
-pub fn register_function(part: &str, name: &str, func: fn(&str) -> i64) {
-    let func_name = format!("{}_{}", name, part);
-    FUNCTION_REGISTRY
-        .write()
-        .expect("Failed to acquire write lock")
-        .insert(func_name, func);
+/// Holds the raw text of the input and, when an `#[aoc_generator]` is registered, the value it
+/// produced, so both parts share a single parse.
+pub struct Input {
+    raw: String,
+    parsed: Option<Box<dyn Any>>,
+}
+
+impl Input {
+    pub fn new(raw: String) -> Self {
+        Input { raw, parsed: None }
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the value produced by the generator, which must be of type `T`.
+    pub fn parsed<T: 'static>(&self) -> Result<&T> {
+        let parsed = self.parsed.as_ref().ok_or_else(|| {
+            miette!(
+                "Solution expects a `{}` input, but no generator is registered",
+                type_name::<T>()
+            )
+        })?;
+
+        parsed.downcast_ref().ok_or_else(|| {
+            miette!(
+                "Solution expects a `{}` input, but the generator produced a different type",
+                type_name::<T>()
+            )
+        })
+    }
 }
 
+pub type SolutionFn = fn(&Input) -> Result<Answer>;
+pub type GeneratorFn = fn(&str) -> Result<Box<dyn Any>>;
+type FunctionRegistry = BTreeMap<SolutionKey, Registered>;
+
 /// Runs the Advent of Code problem solution.
 ///
-/// This function reads the input file, parses the arguments, and runs the solution function.
+/// This function reads the input file, parses the arguments, and runs the solution function
+/// picked from the ones registered in the binary with `#[aoc]`. A relative `input_path` is read
+/// from the current directory, unlike the inputs found by [`run_day`] and [`run_year`].
+///
+/// A failing solution is returned as a [`miette::Report`] carrying the puzzle input as its source
+/// code, so diagnostics with labels but no source of their own point straight into the input.
 pub fn run(input_path: PathBuf, args: AocRunnerArgs) -> Result<()> {
-    let input = std::fs::read_to_string(&input_path)
-        .map_err(|e| anyhow::anyhow!("Failed to read input file: {:?}", e))?;
-
-    let registry = FUNCTION_REGISTRY
-        .read()
-        .expect("Failed to acquire read lock");
-
-    let named_func = registry.get(format!("{}_{}", args.name, args.part).as_str());
-    if args.name != "solution" && named_func.is_none() {
-        return Err(anyhow!(
-            "Unable to find a function for the given part and name"
+    if args.day.is_some() || args.all {
+        return Err(miette!(
+            "--day and --all only apply to binaries built with every day of a year"
         ));
     }
 
-    let (func_name, func) = match named_func {
-        Some(func) => Ok((&args.name, func)),
+    run_with(&Registry::linked()?, input_path, args)
+}
+
+/// Runs the solutions of a binary built for a single day, like [`run`] does.
+///
+/// The input is found with [`input_path`] in [`inputs_dir`], from the workspace of the crate at
+/// `manifest_dir`. This is what `aocr::main!(year, day)` calls.
+pub fn run_day(year: u16, day: u8, manifest_dir: &str, args: AocRunnerArgs) -> Result<()> {
+    let inputs_dir = inputs_dir(Path::new(manifest_dir));
+    run(input_path(&inputs_dir, year, day), args)
+}
+
+/// Runs the day picked with `--day`, or every day with `--all`, out of a binary built with every
+/// day of the year.
+///
+/// Inputs are found with [`input_path`] in [`inputs_dir`], from the workspace of the crate at
+/// `manifest_dir`. This is what `aocr::main!(year)` calls.
+pub fn run_year(year: u16, manifest_dir: &str, args: AocRunnerArgs) -> Result<()> {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None if args.all || args.list => Registry::linked_days(),
         None => {
-            let any_func = registry
-                .iter()
-                .filter(|(k, _)| k.ends_with(args.part.as_str()))
-                .collect::<Vec<_>>();
-
-            if any_func.is_empty() {
-                Err(anyhow!("No functions found for part {}", args.part))
-            } else if any_func.len() > 1 {
-                Err(anyhow!("Multiple functions found for part {}", args.part))
-            } else {
-                Ok((any_func[0].0, any_func[0].1))
+            return Err(miette!(
+                "Pick a day to run with --day, or run them all with --all"
+            ))
+        }
+    };
+
+    let mut failures = vec![];
+    for &day in &days {
+        if args.day.is_none() && args.output == OutputFormat::Pretty {
+            println!("{}\n", format!("🎄 Day {:02}", day).bold().purple());
+        }
+
+        let input_path = input_path(&inputs_dir(Path::new(manifest_dir)), year, day);
+        let result = Registry::linked_for_day(day)
+            .and_then(|registry| run_with(&registry, input_path, args.clone()));
+
+        match result {
+            Ok(()) => {}
+            Err(report) if args.day.is_none() => {
+                eprintln!("{:?}", report);
+                failures.push(format!("{:02}", day));
             }
+            Err(report) => return Err(report),
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(miette!("Days failed: {}", failures.join(", ")))
+    }
+}
+
+/// Defines the `main` of a binary built with every day of a year, or with a single day.
+///
+/// With every day, each one lives in a `dayNN` module declared next to it, and is picked with
+/// `--day` or run along with all the others with `--all`.
+///
+/// ```ignore
+/// mod day01;
+/// mod day02;
+///
+/// aocr::main!(2024);
+/// ```
+///
+/// A binary built for a single day gives the day along with the year, as in `aocr::main!(2024, 1)`.
+#[macro_export]
+macro_rules! main {
+    ($year:literal) => {
+        fn main() -> $crate::runner::__private::miette::Result<()> {
+            use $crate::runner::__private::clap::Parser;
+
+            $crate::runner::run_year(
+                $year,
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::runner::AocRunnerArgs::parse(),
+            )
+        }
+    };
+    ($year:literal, $day:literal) => {
+        fn main() -> $crate::runner::__private::miette::Result<()> {
+            use $crate::runner::__private::clap::Parser;
+
+            $crate::runner::run_day(
+                $year,
+                $day,
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::runner::AocRunnerArgs::parse(),
+            )
+        }
+    };
+}
+
+/// The directory the inputs are stored in, organized as `{year}/{day}.txt`.
+///
+/// This is `AOCR_INPUTS_DIR` when set, and otherwise the `inputs` directory of the workspace the
+/// crate at `manifest_dir` belongs to, so binaries find their inputs from any directory.
+pub fn inputs_dir(manifest_dir: &Path) -> PathBuf {
+    inputs_dir_from(manifest_dir, env::var_os("AOCR_INPUTS_DIR"))
+}
+
+/// The inputs directory of [`inputs_dir`], unless `overridden` with another one.
+fn inputs_dir_from(manifest_dir: &Path, overridden: Option<OsString>) -> PathBuf {
+    if let Some(dir) = overridden {
+        return PathBuf::from(dir);
+    }
+
+    manifest_dir
+        .ancestors()
+        .map(|dir| dir.join("inputs"))
+        .find(|dir| dir.is_dir())
+        .unwrap_or_else(|| PathBuf::from("inputs"))
+}
+
+/// The input of the day, stored as `{year}/{day}.txt` in the given inputs directory, usually the
+/// one of [`inputs_dir`].
+pub fn input_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+/// Runs the solution like [`run`], picking it from the given registry.
+pub fn run_with(registry: &Registry, input_path: PathBuf, args: AocRunnerArgs) -> Result<()> {
+    // Nothing printed along with plain or JSON output is colored, for the tools reading it, but
+    // only for this run, so later pretty runs in the same process keep their colors.
+    if args.output == OutputFormat::Pretty {
+        return run_selected(registry, input_path, args);
+    }
+
+    colored::control::set_override(false);
+    let result = run_selected(registry, input_path, args);
+    colored::control::unset_override();
+
+    result
+}
+
+/// Runs the solutions selected by the arguments, in the mode they pick.
+fn run_selected(registry: &Registry, input_path: PathBuf, args: AocRunnerArgs) -> Result<()> {
+    if args.list {
+        list::print(
+            &Listing::from_registry(registry, args.part.as_ref()),
+            args.output,
+        );
+        return Ok(());
+    }
+
+    let input_path = resolve_input_path(input_path, &args)?;
+
+    if args.all_profiles {
+        profiles::run(
+            registry,
+            &input_path,
+            &find_solutions(registry, &args)?,
+            &args,
+        )?;
+        return check_ambiguous(registry, &args);
+    }
+
+    if args.example {
+        examples::run(
+            registry,
+            &input_path,
+            &find_solutions(registry, &args)?,
+            &args,
+        )?;
+        return check_ambiguous(registry, &args);
+    }
+
+    let prepared = prepare(registry, &input_path, &args)?;
+
+    if args.bench {
+        bench::bench(&prepared, &args)?;
+        return check_ambiguous(registry, &args);
+    }
+
+    let mut answers = load_answers(&input_path)?;
+
+    if args.all_variants {
+        return variants::compare(&prepared, &answers, &args);
+    }
+
+    let report = prepared.run(|part| answers.get(part));
+    output::print(&report, args.output, args.no_timing);
+
+    let mut output = None;
+    for solution in &report.solutions {
+        let Ok(answer) = &solution.answer else {
+            continue;
+        };
+        if args.accept {
+            answers.record(&solution.part, answer);
+        }
+        answers.record_last(&solution.part, answer);
+        output = Some(answer);
+    }
+
+    if output.is_some() && input_path.as_os_str() != "-" {
+        answers.save()?;
+    }
+    // JSON output is read by scripts, which neither need the notices nor the clipboard.
+    if args.output != OutputFormat::Json {
+        if args.accept {
+            println!("{}", "Answers recorded as accepted.".blue().italic());
         }
-    }?;
 
-    println!(
-        "🌟 Part {} for {}:\n",
-        args.part.as_str().cyan().bold(),
-        func_name.cyan().bold(),
-    );
-    let output = func(&input);
+        if let Some(output) = output {
+            copy_to_clipboard(output);
+        }
+    }
+
+    let regressions = report
+        .failures()
+        .filter(|solution| solution.status() == Status::Incorrect)
+        .map(|solution| format!("{} (part {})", solution.name, solution.part))
+        .collect::<Vec<_>>();
+    if let Some(error) = report.into_error() {
+        return Err(error);
+    }
+
+    if !regressions.is_empty() {
+        return Err(miette!(
+            "Answers don't match the accepted ones: {}",
+            regressions.join(", ")
+        ));
+    }
+
+    check_ambiguous(registry, &args)
+}
+
+/// Runs the selected solutions once and reports how they did, checked against the answers
+/// accepted for the input.
+///
+/// Unlike [`run_with`], nothing is printed, recorded or copied to the clipboard, which leaves
+/// presenting the report to the caller. The input is found from `input_path` and the arguments
+/// the same way, but the modes running more than once, such as `--bench`, are ignored. Without
+/// `--part`, the parts with several functions to pick from are left out of the report.
+pub fn report(registry: &Registry, input_path: PathBuf, args: &AocRunnerArgs) -> Result<RunReport> {
+    let input_path = resolve_input_path(input_path, args)?;
+    let prepared = prepare(registry, &input_path, args)?;
+    let answers = load_answers(&input_path)?;
+
+    Ok(prepared.run(|part| answers.get(part)))
+}
+
+/// The input picked by `--input` or `--profile`, falling back to the given one.
+fn resolve_input_path(input_path: PathBuf, args: &AocRunnerArgs) -> Result<PathBuf> {
+    let input_path = match (&args.input, &args.profile) {
+        (Some(input), _) => input.clone(),
+        (None, Some(profile)) => profiles::profile_path(&input_path, profile),
+        (None, None) => input_path,
+    };
+    if input_path.as_os_str() == "-" && args.accept {
+        return Err(miette!(
+            "--accept needs an input file to record the answers next to"
+        ));
+    }
+
+    Ok(input_path)
+}
+
+/// Reads the input and runs the generator over it, ready for the selected solutions.
+fn prepare(registry: &Registry, input_path: &Path, args: &AocRunnerArgs) -> Result<Prepared> {
+    let input = read_input(input_path)?;
+    let solutions = find_solutions(registry, args)?;
 
-    println!(
-        "{} {}",
-        "Answer:".italic(),
-        format!("{output}").green().bold()
-    );
+    Prepared::new(registry, input_path, input, solutions)
+}
 
+/// The answers recorded for the input, which are never any when reading stdin.
+fn load_answers(input_path: &Path) -> Result<AnswersFile> {
+    match input_path.as_os_str() == "-" {
+        true => Ok(AnswersFile::default()),
+        false => AnswersFile::load(input_path),
+    }
+}
+
+/// Reads the puzzle input from the file, or from stdin when the path is `-`.
+fn read_input(input_path: &Path) -> Result<String> {
+    if input_path.as_os_str() == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| miette!("Failed to read input from stdin: {:?}", e))?;
+        return Ok(input);
+    }
+
+    std::fs::read_to_string(input_path).map_err(|e| {
+        miette!(
+            "Failed to read input file {}: {:?}",
+            input_path.display(),
+            e
+        )
+    })
+}
+
+/// Calls a solution, returning its answer along with how long it took.
+fn solve(func: SolutionFn, input: &Input, input_path: &Path) -> Result<(Answer, Duration)> {
+    let solve_start = Instant::now();
+    let answer = func(input).map_err(|report| with_source(report, input_path, input.raw()))?;
+
+    Ok((answer, solve_start.elapsed()))
+}
+
+fn copy_to_clipboard(output: &Answer) {
     if let Ok(mut ctx) = ClipboardContext::new() {
         if let Err(e) = ctx.set_contents(output.to_string()) {
             eprintln!("Failed to copy to clipboard: {:?}", e);
         } else {
-            println!("{}", "\nOutput copied to clipboard.".blue().italic());
+            println!("{}", "Output copied to clipboard.".blue().italic());
+        }
+    }
+}
+
+/// Runs the registered generator, if any, over the raw input.
+///
+/// Returns the input handed to the solutions together with the time spent generating it.
+fn generate_input(
+    registry: &Registry,
+    input: String,
+    input_path: &Path,
+) -> Result<(Input, Option<Duration>)> {
+    let generator = registry.generator();
+    let parse_start = Instant::now();
+    let parsed = generator
+        .map(|generator| generator(&input))
+        .transpose()
+        .map_err(|report| with_source(report, input_path, &input))?;
+    let parse_time = generator.map(|_| parse_start.elapsed());
+
+    Ok((Input { raw: input, parsed }, parse_time))
+}
+
+/// Looks up the solution functions to run, in part order.
+///
+/// When no part is given, every part with a matching function is selected, leaving out the parts
+/// with several functions to pick from, which [`check_ambiguous`] reports after the others ran.
+fn find_solutions(
+    registry: &Registry,
+    args: &AocRunnerArgs,
+) -> Result<Vec<(Part, String, SolutionFn)>> {
+    let parts = match &args.part {
+        Some(part) => vec![part.clone()],
+        None => Part::value_variants().to_vec(),
+    };
+
+    let mut solutions = vec![];
+    for part in parts {
+        let variants = find_variants(registry.functions(), &part);
+        if args.all_variants {
+            if args.part.is_some() && variants.is_empty() {
+                return Err(miette!("No functions found for part {}", part));
+            }
+
+            solutions.extend(
+                variants
+                    .into_iter()
+                    .map(|(name, func)| (part.clone(), name, func)),
+            );
+            continue;
+        }
+
+        for name in &args.names {
+            let registered = match name.as_str() {
+                "solution" => !variants.is_empty(),
+                name => variants.iter().any(|(variant, _)| variant == name),
+            };
+            if args.part.is_none() && !registered {
+                continue;
+            }
+
+            let found = find_function(&variants, &part, name);
+            // Without a part, an ambiguous one is left for `check_ambiguous` once the others ran.
+            if args.part.is_none() && name == "solution" && found.is_err() {
+                continue;
+            }
+
+            let (func_name, func) = found?;
+            solutions.push((part.clone(), func_name, func));
+        }
+    }
+
+    if solutions.is_empty() {
+        check_ambiguous(registry, args)?;
+        let names = registry
+            .keys()
+            .map(|key| key.name.as_str())
+            .collect::<Vec<_>>();
+        let suggestion = args
+            .names
+            .iter()
+            .find_map(|name| did_you_mean(name, names.iter().copied()));
+        Err(miette!(
+            "Unable to find a function for the given name{}",
+            suggestion.map_or_else(String::new, |name| format!(", did you mean {}?", name))
+        ))
+    } else {
+        Ok(solutions)
+    }
+}
+
+/// Fails for the first part [`find_solutions`] left out because it has several functions and none
+/// of them was picked, which is only done when no part is given.
+fn check_ambiguous(registry: &Registry, args: &AocRunnerArgs) -> Result<()> {
+    if args.part.is_some() || args.all_variants || !args.names.iter().any(|name| name == "solution")
+    {
+        return Ok(());
+    }
+
+    for part in Part::value_variants() {
+        let variants = find_variants(registry.functions(), part);
+        if !variants.is_empty() {
+            find_function(&variants, part, "solution")?;
         }
     }
 
     Ok(())
 }
 
+/// Every function registered for the part, sorted by name.
+fn find_variants(registry: &FunctionRegistry, part: &Part) -> Vec<(String, SolutionFn)> {
+    let mut variants = registry
+        .iter()
+        .filter(|(key, _)| key.part == *part)
+        .map(|(key, registered)| (key.name.clone(), registered.func))
+        .collect::<Vec<_>>();
+    variants.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    variants
+}
+
+/// Picks the variant with the exact name, or the only variant of the part for `solution`.
+fn find_function(
+    variants: &[(String, SolutionFn)],
+    part: &Part,
+    name: &str,
+) -> Result<(String, SolutionFn)> {
+    if let Some((name, func)) = variants.iter().find(|(variant, _)| variant == name) {
+        return Ok((name.clone(), *func));
+    }
+    if name != "solution" {
+        let names = variants.iter().map(|(variant, _)| variant.as_str());
+        return Err(miette!(
+            "Unable to find a function for the given part and name{}",
+            did_you_mean(name, names)
+                .map_or_else(String::new, |name| format!(", did you mean {}?", name))
+        ));
+    }
+
+    match variants {
+        [] => Err(miette!("No functions found for part {}", part)),
+        [(name, func)] => Ok((name.clone(), *func)),
+        _ => Err(miette!(
+            "Multiple functions found for part {}, pick one of {} with --name",
+            part,
+            variants
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// The registered name closest to a mistyped one, when any is close enough.
+///
+/// Names are close when a few letters were added, removed, replaced or swapped, about one per
+/// three letters of the name, and at least one.
+fn did_you_mean<'a>(name: &str, names: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    names
+        .map(|candidate| (strsim::damerau_levenshtein(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Formats a duration with the unit that keeps it readable, e.g. `12.35 µs` or `1.20 s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{} ns", nanos),
+        1_000..1_000_000 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", duration.as_secs_f64()),
+    }
+}
+
+/// Attaches the puzzle input to a report so its labels can point into it.
+fn with_source(report: miette::Report, input_path: &Path, input: &str) -> miette::Report {
+    report.with_source_code(NamedSource::new(
+        input_path.display().to_string(),
+        input.to_string(),
+    ))
+}
+
+#[doc(hidden)]
+pub mod __private {
+    //! Support for the code generated from `#[aoc]`.
+    //!
+    //! Solutions may fail with a [`miette::Report`], any [`miette::Diagnostic`] or anything that
+    //! converts into a boxed [`std::error::Error`] (`anyhow::Error`, `std::io::Error`, ...). Which
+    //! conversion applies is picked through autoref specialization, the same trick `anyhow!` uses,
+    //! so diagnostics keep their labels instead of being flattened into plain errors.
+    //!
+    //! `clap`, `linkme` and `miette` are re-exported so the generated code doesn't rely on
+    //! solution crates depending on them.
+
+    use std::{error::Error, fmt::Display};
+
+    pub use clap;
+    pub use linkme;
+    pub use miette;
+
+    use miette::{Diagnostic, Report};
+
+    pub struct ReportKind;
+
+    pub trait ReportKindTrait {
+        fn aocr_error_kind(&self) -> ReportKind {
+            ReportKind
+        }
+    }
+
+    impl ReportKindTrait for Report {}
+
+    impl ReportKind {
+        pub fn into_report(self, error: Report) -> Report {
+            error
+        }
+    }
+
+    pub struct DiagnosticKind;
+
+    pub trait DiagnosticKindTrait {
+        fn aocr_error_kind(&self) -> DiagnosticKind {
+            DiagnosticKind
+        }
+    }
+
+    impl<E> DiagnosticKindTrait for E where E: Diagnostic + Send + Sync + 'static {}
+
+    impl DiagnosticKind {
+        pub fn into_report<E>(self, error: E) -> Report
+        where
+            E: Diagnostic + Send + Sync + 'static,
+        {
+            Report::new(error)
+        }
+    }
+
+    pub struct BoxedKind;
+
+    pub trait BoxedKindTrait {
+        fn aocr_error_kind(&self) -> BoxedKind {
+            BoxedKind
+        }
+    }
+
+    impl<E> BoxedKindTrait for &E where E: Into<Box<dyn Error + Send + Sync>> {}
+
+    impl BoxedKind {
+        pub fn into_report<E>(self, error: E) -> Report
+        where
+            E: Into<Box<dyn Error + Send + Sync>>,
+        {
+            Report::new(BoxedError(error.into()))
+        }
+    }
+
+    #[derive(Debug)]
+    struct BoxedError(Box<dyn Error + Send + Sync>);
+
+    impl Display for BoxedError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            Display::fmt(&self.0, f)
+        }
+    }
+
+    impl Error for BoxedError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.0.source()
+        }
+    }
+
+    impl Diagnostic for BoxedError {}
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
     use std::path::PathBuf;
 
     #[test]
@@ -158,38 +838,77 @@ mod tests {
         assert_eq!(Part::Two.as_str(), "two");
     }
 
+    #[test]
+    fn test_part_level() {
+        assert_eq!(Part::One.level(), 1);
+        assert_eq!(Part::Two.level(), 2);
+    }
+
     #[test]
     fn test_part_display() {
         assert_eq!(format!("{}", Part::One), "one");
         assert_eq!(format!("{}", Part::Two), "two");
     }
 
-    fn sample_solution(input: &str) -> i64 {
-        input.parse().unwrap_or(0)
+    fn sample_solution(input: &Input) -> Result<Answer> {
+        Ok(Answer::new(input.raw().parse::<i64>().unwrap_or(0)))
+    }
+
+    #[test]
+    fn test_input_parsed() {
+        let mut input = Input::new("1\n2\n3".to_string());
+        assert!(input.parsed::<Vec<i64>>().is_err());
+
+        input.parsed = Some(Box::new(vec![1_i64, 2, 3]));
+        assert_eq!(input.parsed::<Vec<i64>>().unwrap(), &vec![1, 2, 3]);
+        assert_eq!(input.raw(), "1\n2\n3");
+
+        let error = input.parsed::<String>().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("the generator produced a different type"));
+    }
+
+    #[derive(Debug, thiserror::Error, miette::Diagnostic)]
+    #[error("Unexpected character")]
+    struct UnexpectedChar {
+        #[label("this one")]
+        at: miette::SourceSpan,
+    }
+
+    fn failing_solution(_input: &Input) -> Result<Answer> {
+        Err(UnexpectedChar { at: (1, 1).into() }.into())
     }
 
     #[test]
-    fn test_register_function() {
-        let name = "solution";
-        register_function(Part::One.as_str(), name, sample_solution);
+    fn test_answer_from_display() {
+        assert_eq!(Answer::new(42_i64).as_str(), "42");
+        assert_eq!(Answer::new(u128::MAX).as_str(), u128::MAX.to_string());
+        assert_eq!(Answer::new(String::from("EHZERUFJ")).as_str(), "EHZERUFJ");
+        assert_eq!(
+            Answer::new("4,6,3,5,6,3,5,2,1,0").as_str(),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+    }
 
-        let registry = FUNCTION_REGISTRY.read().unwrap();
-        let func_key = format!("{}_{}", name, Part::One.as_str());
-        assert!(registry.contains_key(&func_key));
+    #[test]
+    fn test_registry_with_solution() {
+        let registry = Registry::new().with_solution(Part::One, "solution", sample_solution);
+
+        assert!(registry.get(&Part::One, "solution").is_some());
+        assert!(registry.get(&Part::Two, "solution").is_none());
     }
 
     #[test]
     fn test_run_registered_function() {
-        let name = "solution";
-        register_function(Part::One.as_str(), name, sample_solution);
+        let registry = Registry::new().with_solution(Part::One, "solution", sample_solution);
 
-        let registry = FUNCTION_REGISTRY.read().unwrap();
         let func = registry
-            .get(&format!("{}_{}", name, Part::One.as_str()))
+            .get(&Part::One, "solution")
             .expect("Function not found in registry");
 
-        let result = func("42");
-        assert_eq!(result, 42);
+        let result = func(&Input::new("42".to_string())).unwrap();
+        assert_eq!(result, Answer::new(42));
     }
 
     #[test]
@@ -197,16 +916,302 @@ mod tests {
         let input_path = PathBuf::from("test_input_mocked.txt");
         std::fs::write(&input_path, "123").expect("Failed to create test input file");
 
-        register_function(Part::One.as_str(), "solution", sample_solution);
+        let registry = Registry::new().with_solution(Part::One, "solution", sample_solution);
         let args = vec!["binary_name", "--part", "one", "--name", "solution"];
         let aoc_args = AocRunnerArgs::parse_from(args);
 
-        let result = run(input_path, aoc_args);
+        let result = run_with(&registry, input_path, aoc_args);
 
         assert!(result.is_ok());
 
         // Cleanup: removing test file
         std::fs::remove_file("test_input_mocked.txt").unwrap();
+        std::fs::remove_file("test_input_mocked.answers.toml").unwrap();
+    }
+
+    #[rstest]
+    #[case(Duration::from_nanos(845), "845 ns")]
+    #[case(Duration::from_nanos(12_345), "12.35 µs")]
+    #[case(Duration::from_micros(3_210), "3.21 ms")]
+    #[case(Duration::from_millis(1_200), "1.20 s")]
+    #[case(Duration::from_secs(75), "75.00 s")]
+    fn test_format_duration(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(format_duration(duration), expected);
+    }
+
+    #[test]
+    fn test_run_without_part() {
+        let input_path = PathBuf::from("test_input_all_parts.txt");
+        std::fs::write(&input_path, "123").expect("Failed to create test input file");
+
+        let registry = Registry::new()
+            .with_solution(Part::One, "both_parts", sample_solution)
+            .with_solution(Part::Two, "both_parts", sample_solution);
+        let args = vec!["binary_name", "--name", "both_parts", "--no-timing"];
+        let aoc_args = AocRunnerArgs::parse_from(args);
+
+        let solutions = find_solutions(&registry, &aoc_args).unwrap();
+        let parts = solutions.iter().map(|(part, ..)| part).collect::<Vec<_>>();
+        assert_eq!(parts, vec![&Part::One, &Part::Two]);
+
+        let result = run_with(&registry, input_path, aoc_args);
+
+        assert!(result.is_ok());
+
+        // Cleanup
+        std::fs::remove_file("test_input_all_parts.txt").unwrap();
+        std::fs::remove_file("test_input_all_parts.answers.toml").unwrap();
+    }
+
+    #[test]
+    fn test_find_all_variants() {
+        let registry = Registry::new()
+            .with_solution(Part::Two, "variant_b", sample_solution)
+            .with_solution(Part::Two, "variant_a", sample_solution)
+            .with_solution(Part::One, "other_part", sample_solution);
+        let args = vec!["binary_name", "--part", "two", "--all-variants"];
+        let aoc_args = AocRunnerArgs::parse_from(args);
+
+        let solutions = find_solutions(&registry, &aoc_args).unwrap();
+        let names = solutions
+            .iter()
+            .map(|(_, name, _)| name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["variant_a", "variant_b"]);
+        assert!(solutions.iter().all(|(part, ..)| *part == Part::Two));
+    }
+
+    #[test]
+    fn test_find_ambiguous_solution() {
+        let registry = Registry::new()
+            .with_solution(Part::One, "closures", sample_solution)
+            .with_solution(Part::One, "for_loop", sample_solution)
+            .with_solution(Part::Two, "for_loop", sample_solution);
+        let args = vec!["binary_name", "--part", "one"];
+
+        let error = find_solutions(&registry, &AocRunnerArgs::parse_from(args)).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Multiple functions found for part one, pick one of closures, for_loop with --name"
+        );
+    }
+
+    #[test]
+    fn test_run_with_ambiguous_part() {
+        let input_path = PathBuf::from("test_input_ambiguous.txt");
+        std::fs::write(&input_path, "5").expect("Failed to create test input file");
+
+        let registry = Registry::new()
+            .with_solution(Part::One, "solution", sample_solution)
+            .with_solution(Part::Two, "closures", sample_solution)
+            .with_solution(Part::Two, "for_loop", sample_solution);
+        let aoc_args = AocRunnerArgs::parse_from(["binary_name", "--no-timing"]);
+
+        let solutions = find_solutions(&registry, &aoc_args).unwrap();
+        let parts = solutions.iter().map(|(part, ..)| part).collect::<Vec<_>>();
+        assert_eq!(parts, vec![&Part::One]);
+
+        let error = run_with(&registry, input_path.clone(), aoc_args).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Multiple functions found for part two, pick one of closures, for_loop with --name"
+        );
+        let answers = AnswersFile::load(&input_path).unwrap();
+        assert_eq!(answers.last(&Part::One), Some(Answer::new(5)));
+
+        // Cleanup
+        std::fs::remove_file("test_input_ambiguous.txt").unwrap();
+        std::fs::remove_file("test_input_ambiguous.answers.toml").unwrap();
+    }
+
+    #[test]
+    fn test_find_mistyped_solution() {
+        let registry = Registry::new()
+            .with_solution(Part::One, "closures", sample_solution)
+            .with_solution(Part::Two, "for_loop", sample_solution);
+
+        let args = vec!["binary_name", "--part", "one", "--name", "closure"];
+        let error = find_solutions(&registry, &AocRunnerArgs::parse_from(args)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unable to find a function for the given part and name, did you mean closures?"
+        );
+
+        let args = vec!["binary_name", "--name", "forloop"];
+        let error = find_solutions(&registry, &AocRunnerArgs::parse_from(args)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unable to find a function for the given name, did you mean for_loop?"
+        );
+
+        let registry = registry.with_solution(Part::One, "nom", sample_solution);
+        let args = vec!["binary_name", "--part", "one", "--name", "nmo"];
+        let error = find_solutions(&registry, &AocRunnerArgs::parse_from(args)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unable to find a function for the given part and name, did you mean nom?"
+        );
+
+        let args = vec!["binary_name", "--name", "unrelated"];
+        let error = find_solutions(&registry, &AocRunnerArgs::parse_from(args)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unable to find a function for the given name"
+        );
+    }
+
+    #[test]
+    fn test_run_with_accepted_answers() {
+        let input_path = PathBuf::from("test_input_accepted.txt");
+        std::fs::write(&input_path, "7").expect("Failed to create test input file");
+
+        let registry = Registry::new().with_solution(Part::One, "accepted", sample_solution);
+        let run_accepted = |extra: &[&str]| {
+            let mut args = vec!["binary_name", "--part", "one", "--name", "accepted"];
+            args.extend(extra);
+            run_with(
+                &registry,
+                input_path.clone(),
+                AocRunnerArgs::parse_from(args),
+            )
+        };
+
+        assert!(run_accepted(&["--accept"]).is_ok());
+        assert!(run_accepted(&[]).is_ok());
+
+        std::fs::write(&input_path, "8").expect("Failed to update test input file");
+        let error = run_accepted(&[]).expect_err("Changed answer should be reported");
+        assert!(error.to_string().contains("accepted (part one)"));
+
+        // Cleanup
+        std::fs::remove_file("test_input_accepted.txt").unwrap();
+        std::fs::remove_file("test_input_accepted.answers.toml").unwrap();
+    }
+
+    #[test]
+    fn test_run_with_examples() {
+        let input_path = PathBuf::from("test_input_examples.txt");
+        let example_path = PathBuf::from("test_input_examples.example1.txt");
+        std::fs::write(&example_path, "---\none = 5\n---\n5").expect("Failed to create example");
+
+        let registry = Registry::new().with_solution(Part::One, "examples", sample_solution);
+        let run_example = || {
+            let args = vec![
+                "binary_name",
+                "--part",
+                "one",
+                "--name",
+                "examples",
+                "--example",
+            ];
+            run_with(
+                &registry,
+                input_path.clone(),
+                AocRunnerArgs::parse_from(args),
+            )
+        };
+
+        // The input itself doesn't exist, only the example is read.
+        assert!(run_example().is_ok());
+
+        std::fs::write(&example_path, "---\none = 6\n---\n5").expect("Failed to update example");
+        let error = run_example().expect_err("Wrong answer should be reported");
+        assert!(error.to_string().contains("examples (part one, example1)"));
+        assert!(!input_path.with_extension("answers.toml").exists());
+
+        // Cleanup
+        std::fs::remove_file(example_path).unwrap();
+    }
+
+    #[test]
+    fn test_run_with_example_for_one_part() {
+        let input_path = PathBuf::from("test_input_one_part.txt");
+        let example_path = PathBuf::from("test_input_one_part.example1.txt");
+        std::fs::write(&example_path, "---\none = 3\n---\n3").expect("Failed to create example");
+
+        let registry = Registry::new()
+            .with_solution(Part::One, "solution", sample_solution)
+            .with_solution(Part::Two, "solution", failing_solution);
+        let run_example = |part: &[&str]| {
+            let mut args = vec!["binary_name", "--example", "--no-timing"];
+            args.extend(part);
+            run_with(
+                &registry,
+                input_path.clone(),
+                AocRunnerArgs::parse_from(args),
+            )
+        };
+
+        // Part two would fail, but the example expects nothing for it.
+        assert!(run_example(&[]).is_ok());
+        let error = run_example(&["--part", "two"]).expect_err("Nothing should be checked");
+        assert!(error.to_string().contains("No examples of"));
+
+        // Cleanup
+        std::fs::remove_file(example_path).unwrap();
+    }
+
+    #[test]
+    fn test_run_without_examples() {
+        let registry = Registry::new().with_solution(Part::One, "no_examples", sample_solution);
+        let args = vec!["binary_name", "--name", "no_examples", "--example"];
+
+        let error = run_with(
+            &registry,
+            PathBuf::from("test_input_no_examples.txt"),
+            AocRunnerArgs::parse_from(args),
+        )
+        .expect_err("Missing examples should be reported");
+
+        assert!(error.to_string().contains("No examples found"));
+    }
+
+    #[test]
+    fn test_run_with_failing_function() {
+        let input_path = PathBuf::from("test_input_failing.txt");
+        std::fs::write(&input_path, "1x3").expect("Failed to create test input file");
+
+        let registry = Registry::new().with_solution(Part::Two, "failing", failing_solution);
+        let args = vec!["binary_name", "--part", "two", "--name", "failing"];
+        let aoc_args = AocRunnerArgs::parse_from(args);
+
+        let result = run_with(&registry, input_path, aoc_args);
+
+        let report = result.expect_err("Failing solution should return an error");
+        assert_eq!(report.to_string(), "Unexpected character");
+        assert!(report.source_code().is_some());
+
+        // Cleanup
+        std::fs::remove_file("test_input_failing.txt").unwrap();
+    }
+
+    #[test]
+    fn test_error_kinds_into_report() {
+        use __private::*;
+
+        let report = miette!("plain report");
+        assert_eq!(
+            report.aocr_error_kind().into_report(report).to_string(),
+            "plain report"
+        );
+
+        let diagnostic = UnexpectedChar { at: (0, 1).into() };
+        let report = diagnostic.aocr_error_kind().into_report(diagnostic);
+        assert_eq!(report.labels().map(|labels| labels.count()), Some(1));
+
+        let error = anyhow::anyhow!("root cause").context("outer context");
+        let report = (&error).aocr_error_kind().into_report(error);
+        assert_eq!(report.to_string(), "outer context");
+        assert_eq!(
+            report.source().map(ToString::to_string),
+            Some("root cause".into())
+        );
+
+        let error = "x".parse::<i64>().unwrap_err();
+        let report = (&error).aocr_error_kind().into_report(error);
+        assert_eq!(report.to_string(), "invalid digit found in string");
     }
 
     #[test]
@@ -230,6 +1235,128 @@ mod tests {
         std::fs::remove_file("test_input.txt").unwrap();
     }
 
+    #[test]
+    fn test_run_with_input_arg() {
+        let input_path = PathBuf::from("test_input_arg.txt");
+        std::fs::write(&input_path, "5").expect("Failed to create test input file");
+
+        let registry = Registry::new().with_solution(Part::One, "input_arg", sample_solution);
+        let args = vec![
+            "binary_name",
+            "--name",
+            "input_arg",
+            "--input",
+            "test_input_arg.txt",
+        ];
+
+        let result = run_with(
+            &registry,
+            PathBuf::from("non_existent_file.txt"),
+            AocRunnerArgs::parse_from(args),
+        );
+
+        assert!(result.is_ok());
+        assert!(input_path.with_extension("answers.toml").exists());
+
+        // Cleanup
+        std::fs::remove_file("test_input_arg.txt").unwrap();
+        std::fs::remove_file("test_input_arg.answers.toml").unwrap();
+    }
+
+    #[test]
+    fn test_report() {
+        let input_path = PathBuf::from("test_report.txt");
+        std::fs::write(&input_path, "7").expect("Failed to create test input file");
+
+        let registry = Registry::new()
+            .with_solution(Part::One, "report", sample_solution)
+            .with_solution(Part::Two, "report", failing_solution);
+        let args = AocRunnerArgs::parse_from(["binary_name", "--name", "report"]);
+
+        let report = report(&registry, input_path.clone(), &args).unwrap();
+
+        assert_eq!(report.input_path, input_path);
+        assert_eq!(report.puzzle, None);
+        assert_eq!(report.solutions.len(), 2);
+        assert_eq!(
+            report.solutions[0].answer.as_ref().ok(),
+            Some(&Answer::new(7))
+        );
+        assert_eq!(report.solutions[0].status(), Status::Unverified);
+        assert_eq!(report.solutions[1].status(), Status::Failed);
+        // Nothing is recorded for a report alone.
+        assert!(!input_path.with_extension("answers.toml").exists());
+
+        // Cleanup
+        std::fs::remove_file(&input_path).unwrap();
+    }
+
+    #[test]
+    fn test_run_with_stdin_doesnt_accept() {
+        let registry = Registry::new().with_solution(Part::One, "stdin", sample_solution);
+        let args = vec!["binary_name", "--name", "stdin", "--input", "-", "--accept"];
+
+        let error = run_with(
+            &registry,
+            PathBuf::from("non_existent_file.txt"),
+            AocRunnerArgs::parse_from(args),
+        )
+        .expect_err("Answers from stdin can't be accepted");
+
+        assert!(error.to_string().contains("--accept needs an input file"));
+    }
+
+    #[test]
+    fn test_run_with_all_profiles() {
+        let input_path = PathBuf::from("test_input_profiles.txt");
+        let dir = PathBuf::from("test_input_profiles");
+        std::fs::create_dir_all(&dir).expect("Failed to create profiles directory");
+        std::fs::write(&input_path, "5").expect("Failed to create test input file");
+        std::fs::write(dir.join("alice.txt"), "6").expect("Failed to create profile input");
+        std::fs::write(dir.join("alice.answers.toml"), "[accepted]\none = \"7\"\n")
+            .expect("Failed to create profile answers");
+
+        let registry = Registry::new().with_solution(Part::One, "profiles", sample_solution);
+        let run_profiles = |extra: &[&str]| {
+            let mut args = vec!["binary_name", "--name", "profiles"];
+            args.extend(extra);
+            run_with(
+                &registry,
+                input_path.clone(),
+                AocRunnerArgs::parse_from(args),
+            )
+        };
+
+        let error = run_profiles(&["--profile", "alice"]).expect_err("Alice's answer is wrong");
+        assert!(error.to_string().contains("profiles (part one)"));
+        let error = run_profiles(&["--all-profiles"]).expect_err("Alice's answer is wrong");
+        assert_eq!(
+            error.to_string(),
+            "Wrong answers for the inputs of alice (profiles part one)"
+        );
+
+        // Cleanup
+        std::fs::remove_file(&input_path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_input_path() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let workspace = manifest_dir.parent().unwrap();
+
+        let inputs_dir = inputs_dir_from(manifest_dir, None);
+        assert_eq!(inputs_dir, workspace.join("inputs"));
+        assert_eq!(
+            input_path(&inputs_dir, 2024, 1),
+            workspace.join("inputs/2024/01.txt")
+        );
+        assert_eq!(
+            inputs_dir_from(manifest_dir, Some("elsewhere".into())),
+            PathBuf::from("elsewhere")
+        );
+    }
+
     #[test]
     fn test_run_with_missing_input_file() {
         let input_path = PathBuf::from("non_existent_file.txt");
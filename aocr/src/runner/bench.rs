@@ -0,0 +1,218 @@
+//! In-process benchmarking of the registered solutions.
+//!
+//! The input is read and generated once, then each solution is called repeatedly so only the
+//! solution itself is measured, without process startup or file I/O.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use colored::*;
+use miette::{miette, Result};
+
+use super::{
+    format_duration, report::Prepared, table::markdown_table, with_source, AocRunnerArgs, Part,
+};
+
+/// Timing statistics over the measured iterations of a solution.
+#[derive(Debug, PartialEq)]
+struct Stats {
+    mean: Duration,
+    median: Duration,
+    std_dev: Duration,
+    min: Duration,
+    max: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let count = samples.len();
+        let nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let median = match count % 2 {
+            0 => (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0,
+            _ => nanos[count / 2],
+        };
+        let variance = match count {
+            1 => 0.0,
+            _ => nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (count - 1) as f64,
+        };
+
+        Stats {
+            mean: Duration::from_nanos(mean.round() as u64),
+            median: Duration::from_nanos(median.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            min: samples[0],
+            max: samples[count - 1],
+        }
+    }
+}
+
+struct BenchResult {
+    part: Part,
+    name: String,
+    stats: Stats,
+}
+
+/// Benchmarks every selected solution and prints a table with the results.
+pub(super) fn bench(prepared: &Prepared, args: &AocRunnerArgs) -> Result<()> {
+    let Prepared {
+        input_path, input, ..
+    } = prepared;
+
+    let mut results = vec![];
+    for (part, name, func) in &prepared.solutions {
+        println!(
+            "⏱️  Benchmarking part {} for {}...",
+            part.as_str().cyan().bold(),
+            name.cyan().bold(),
+        );
+
+        let call = || {
+            func(black_box(input)).map_err(|report| with_source(report, input_path, input.raw()))
+        };
+
+        for _ in 0..args.warmup {
+            black_box(call()?);
+        }
+
+        let mut samples = Vec::with_capacity(args.runs as usize);
+        for _ in 0..args.runs {
+            let start = Instant::now();
+            let answer = call();
+            samples.push(start.elapsed());
+            black_box(answer?);
+        }
+
+        results.push(BenchResult {
+            part: part.clone(),
+            name: name.clone(),
+            stats: Stats::from_samples(&mut samples),
+        });
+    }
+
+    let table = results_table(&results);
+    println!("\n{}", table);
+
+    if let Some(path) = &args.export_markdown {
+        std::fs::write(path, &table)
+            .map_err(|e| miette!("Failed to write benchmark results: {:?}", e))?;
+        println!(
+            "{}",
+            format!("Results written to {}", path.display())
+                .blue()
+                .italic()
+        );
+    }
+
+    Ok(())
+}
+
+/// Renders the results as a Markdown table, relative to the fastest solution of each part.
+fn results_table(results: &[BenchResult]) -> String {
+    let multiple_parts = results.iter().any(|result| result.part != results[0].part);
+
+    let rows = results
+        .iter()
+        .map(|result| {
+            let fastest = results
+                .iter()
+                .filter(|other| other.part == result.part)
+                .map(|other| other.stats.mean)
+                .min()
+                .unwrap_or(result.stats.mean);
+            let relative = match fastest.as_nanos() {
+                0 => 1.0,
+                fastest => result.stats.mean.as_nanos() as f64 / fastest as f64,
+            };
+
+            let name = if multiple_parts {
+                format!("`{}` (part {})", result.name, result.part)
+            } else {
+                format!("`{}`", result.name)
+            };
+
+            vec![
+                name,
+                format_duration(result.stats.mean),
+                format_duration(result.stats.median),
+                format_duration(result.stats.std_dev),
+                format_duration(result.stats.min),
+                format_duration(result.stats.max),
+                format!("{:.2}", relative),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    markdown_table(
+        &[
+            "Solution",
+            "Mean",
+            "Median",
+            "Std. dev.",
+            "Min",
+            "Max",
+            "Relative",
+        ],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples = [5, 1, 3, 2, 4].map(Duration::from_micros);
+
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(
+            stats,
+            Stats {
+                mean: Duration::from_micros(3),
+                median: Duration::from_micros(3),
+                std_dev: Duration::from_nanos(1581),
+                min: Duration::from_micros(1),
+                max: Duration::from_micros(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_from_single_sample() {
+        let mut samples = [Duration::from_millis(2)];
+
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(stats.median, Duration::from_millis(2));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_results_table_relative_to_fastest() {
+        let result = |name: &str, micros| BenchResult {
+            part: Part::One,
+            name: name.to_string(),
+            stats: Stats::from_samples(&mut [Duration::from_micros(micros)]),
+        };
+        let results = [result("solution", 20), result("nom", 10)];
+
+        let table = results_table(&results);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert!(lines[2].starts_with("| `solution` | 20.00 µs |"));
+        assert!(lines[2].ends_with(" 2.00 |"));
+        assert!(lines[3].starts_with("| `nom`      | 10.00 µs |"));
+        assert!(lines[3].ends_with(" 1.00 |"));
+    }
+}
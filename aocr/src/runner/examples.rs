@@ -0,0 +1,179 @@
+//! Runs the solutions against the examples from the puzzle text.
+
+use std::path::Path;
+
+use colored::*;
+use miette::{miette, Result};
+
+use crate::examples::Example;
+
+use super::{
+    find_variants, format_duration, output,
+    report::{Prepared, RunReport, Status},
+    AocRunnerArgs, OutputFormat, Part, Registry, SolutionFn,
+};
+
+/// Runs every selected solution against every example of the input that expects an answer for its
+/// part, checking the answers the examples expect.
+pub(super) fn run(
+    registry: &Registry,
+    input_path: &Path,
+    solutions: &[(Part, String, SolutionFn)],
+    args: &AocRunnerArgs,
+) -> Result<()> {
+    let paths = Example::find(input_path)?;
+    if paths.is_empty() {
+        return Err(miette!(
+            "No examples found for {}, add them as `{}`",
+            input_path.display(),
+            input_path.with_extension("example1.txt").display()
+        ));
+    }
+
+    let mut mismatches = vec![];
+    let mut checked = false;
+    for path in paths {
+        let example = Example::load(&path)?;
+        // Parts the example gives no answer for are left out, as they may not apply to it.
+        let solutions = solutions
+            .iter()
+            .filter(|(part, ..)| example.expected(part).is_some())
+            .cloned()
+            .collect::<Vec<_>>();
+        if solutions.is_empty() {
+            continue;
+        }
+        checked = true;
+
+        let prepared = Prepared::new(
+            registry,
+            example.path(),
+            example.input().to_string(),
+            solutions,
+        )?;
+        let report = RunReport {
+            example: Some(example.name().to_string()),
+            ..prepared.run(|part| example.expected(part).cloned())
+        };
+
+        match args.output {
+            OutputFormat::Pretty => print_pretty(&report, args.no_timing),
+            format => output::print_records(&report, format, args.no_timing),
+        }
+
+        mismatches.extend(
+            report
+                .failures()
+                .filter(|solution| solution.status() == Status::Incorrect)
+                .map(|solution| {
+                    format!(
+                        "{} (part {}, {})",
+                        solution.name,
+                        solution.part,
+                        example.name()
+                    )
+                }),
+        );
+        if let Some(error) = report.into_error() {
+            return Err(error);
+        }
+    }
+
+    if !checked {
+        Err(miette!(
+            "No examples of {} expect an answer for the selected parts",
+            input_path.display()
+        ))
+    } else if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(miette!(
+            "Answers don't match the examples: {}",
+            mismatches.join(", ")
+        ))
+    }
+}
+
+fn print_pretty(report: &RunReport, no_timing: bool) {
+    println!(
+        "🧪 {}:\n",
+        report.example.as_deref().unwrap_or_default().cyan().bold()
+    );
+
+    for solution in &report.solutions {
+        let answer = match &solution.answer {
+            Ok(answer) => answer.as_str().green().bold(),
+            Err(_) => "failed".red().bold(),
+        };
+        print!(
+            "Part {} for {}: {}",
+            solution.part.as_str().cyan(),
+            solution.name.cyan(),
+            answer
+        );
+        match (solution.status(), &solution.expected) {
+            (Status::Correct, _) => print!(" ✅"),
+            (Status::Incorrect, Some(expected)) => {
+                print!(" ❌ {}", format!("(expected {})", expected).red())
+            }
+            _ => {}
+        }
+        if let Some(time) = solution.solve_time.filter(|_| !no_timing) {
+            print!(" {}", format!("({})", format_duration(time)).dimmed());
+        }
+        println!();
+    }
+    println!();
+}
+
+/// Checks every function registered for the day and part against the answer the example expects.
+///
+/// This backs the tests generated by `aocr_macro::aoc_examples!`, which embed the example so it
+/// is rebuilt when the file changes.
+pub fn check_example(path: &str, contents: &str, day: u8, part: &Part) -> Result<()> {
+    let example = Example::parse(Path::new(path), contents)?;
+    let expected = example
+        .expected(part)
+        .ok_or_else(|| miette!("{} has no expected answer for part {}", path, part))?;
+
+    let registry = Registry::linked_for_day(day)?;
+    let variants = find_variants(registry.functions(), part);
+    if variants.is_empty() {
+        return Err(miette!("No functions found for part {}", part));
+    }
+
+    let solutions = variants
+        .into_iter()
+        .map(|(name, func)| (part.clone(), name, func))
+        .collect();
+    let prepared = Prepared::new(
+        &registry,
+        example.path(),
+        example.input().to_string(),
+        solutions,
+    )?;
+    let report = prepared.run(|_| Some(expected.clone()));
+
+    let mismatches = report
+        .failures()
+        .filter_map(|solution| {
+            let answer = solution.answer.as_ref().ok()?;
+            Some(format!("{} answered {}", solution.name, answer))
+        })
+        .collect::<Vec<_>>();
+    if let Some(error) = report.into_error() {
+        return Err(error);
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(miette!(
+            "Expected {} for part {} of {}, but {}",
+            expected,
+            part,
+            example.name(),
+            mismatches.join(", ")
+        ))
+    }
+}
@@ -0,0 +1,187 @@
+//! Lists the registered solutions, so the parts and names of a day are known without reading its
+//! source.
+
+use serde::{Deserialize, Serialize};
+
+use super::{table::markdown_table, OutputFormat, Part, Registry};
+
+/// A registered solution, as `--list` reports it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Listing {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Part,
+    pub name: String,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    /// The doc comment of the function, if it has one.
+    pub doc: Option<String>,
+}
+
+impl Listing {
+    /// Every function of the registry, optionally only the ones of a part.
+    pub fn from_registry(registry: &Registry, part: Option<&Part>) -> Vec<Listing> {
+        registry
+            .iter()
+            .filter(|(key, _)| part.is_none_or(|part| key.part == *part))
+            .map(|(key, registered)| Listing {
+                year: key.puzzle.map(|puzzle| puzzle.year),
+                day: key.puzzle.map(|puzzle| puzzle.day),
+                part: key.part.clone(),
+                name: key.name.clone(),
+                file: registered.location.map(|(file, _)| file.to_string()),
+                line: registered.location.map(|(_, line)| line),
+                doc: Some(registered.doc.trim())
+                    .filter(|doc| !doc.is_empty())
+                    .map(ToString::to_string),
+            })
+            .collect()
+    }
+
+    /// The file and line the function is defined at, as `file:line`.
+    pub fn location(&self) -> String {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => format!("{}:{}", file, line),
+            (Some(file), None) => file.clone(),
+            _ => String::new(),
+        }
+    }
+
+    /// The first line of the doc comment, which sums it up.
+    pub fn summary(&self) -> &str {
+        self.doc
+            .as_deref()
+            .and_then(|doc| doc.lines().next())
+            .unwrap_or_default()
+    }
+}
+
+/// Renders the listings as a Markdown table, with a column for the day when they span several.
+pub fn table(listings: &[Listing]) -> String {
+    let with_day = listings
+        .iter()
+        .any(|listing| listing.day != listings[0].day);
+
+    let rows = listings
+        .iter()
+        .map(|listing| {
+            let mut row = vec![];
+            if with_day {
+                row.push(
+                    listing
+                        .day
+                        .map(|day| format!("{:02}", day))
+                        .unwrap_or_default(),
+                );
+            }
+            row.extend([
+                listing.part.to_string(),
+                format!("`{}`", listing.name),
+                listing.location(),
+                listing.summary().to_string(),
+            ]);
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let header: &[&str] = if with_day {
+        &["Day", "Part", "Name", "Location", "Description"]
+    } else {
+        &["Part", "Name", "Location", "Description"]
+    };
+
+    markdown_table(header, &rows)
+}
+
+/// Prints the listings in the given format, a line per solution unless it is pretty.
+pub(super) fn print(listings: &[Listing], format: OutputFormat) {
+    match format {
+        OutputFormat::Pretty => println!("{}", table(listings)),
+        OutputFormat::Plain => {
+            for listing in listings {
+                let line = format!(
+                    "part {} {} {} {}",
+                    listing.part,
+                    listing.name,
+                    listing.location(),
+                    listing.summary()
+                );
+                println!("{}", line.trim_end());
+            }
+        }
+        OutputFormat::Json => {
+            for listing in listings {
+                println!(
+                    "{}",
+                    serde_json::to_string(listing).expect("Listings serialize to JSON")
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{
+        registry::{Generator, Solution},
+        Answer, Input,
+    };
+    use miette::Result;
+    use pretty_assertions::assert_eq;
+
+    fn solution(_input: &Input) -> Result<Answer> {
+        Ok(Answer::new(1))
+    }
+
+    #[test]
+    fn test_from_registry() {
+        let registered = Solution {
+            year: None,
+            day: None,
+            package: "aoc_2024",
+            bin: None,
+            module: "aoc_2024::day01",
+            file: "aoc_2024/src/day01/mod.rs",
+            line: 4,
+            doc: "Sums the distances.\n\nMore details.",
+            part: Part::One,
+            name: "solution",
+            func: solution,
+        };
+        let solutions = [
+            registered.clone(),
+            Solution {
+                part: Part::Two,
+                name: "fast",
+                doc: "",
+                ..registered
+            },
+        ];
+        let registry = Registry::from_slices(&solutions, &[] as &[Generator], None).unwrap();
+
+        let listings = Listing::from_registry(&registry, None);
+        assert_eq!(
+            listings[0],
+            Listing {
+                year: Some(2024),
+                day: Some(1),
+                part: Part::One,
+                name: "solution".to_string(),
+                file: Some("aoc_2024/src/day01/mod.rs".to_string()),
+                line: Some(4),
+                doc: Some("Sums the distances.\n\nMore details.".to_string()),
+            }
+        );
+        assert_eq!(listings[1].doc, None);
+        assert_eq!(Listing::from_registry(&registry, Some(&Part::Two)).len(), 1);
+
+        let expected = "\
+| Part |       Name |                    Location |         Description |
+| :--- | ---------: | --------------------------: | ------------------: |
+| one  | `solution` | aoc_2024/src/day01/mod.rs:4 | Sums the distances. |
+| two  |     `fast` | aoc_2024/src/day01/mod.rs:4 |                     |
+";
+        assert_eq!(table(&listings), expected);
+    }
+}
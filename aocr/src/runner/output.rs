@@ -0,0 +1,269 @@
+//! Presentation of the run reports, for people or in a form other tools can read.
+
+use std::time::Duration;
+
+use clap::ValueEnum;
+use colored::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    format_duration,
+    report::{RunReport, SolutionReport, Status},
+    Part,
+};
+
+/// How the runner reports the results.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colored text with emoji, for people.
+    #[default]
+    Pretty,
+    /// A line of uncolored text per result.
+    Plain,
+    /// A JSON object per line for each result.
+    Json,
+}
+
+/// Prints the report of a run in the given format.
+pub(super) fn print(report: &RunReport, format: OutputFormat, no_timing: bool) {
+    match format {
+        OutputFormat::Pretty => print_pretty(report, no_timing),
+        _ => {
+            if let Some(parse_time) = report.parse_time.filter(|_| !no_timing) {
+                if format == OutputFormat::Plain {
+                    println!("Parsed in {}", format_duration(parse_time));
+                }
+            }
+            print_records(report, format, no_timing);
+        }
+    }
+}
+
+/// Prints a line per solution of the report, in a format that shouldn't be pretty.
+pub(super) fn print_records(report: &RunReport, format: OutputFormat, no_timing: bool) {
+    for solution in &report.solutions {
+        let record = Record::new(report, solution);
+        match no_timing {
+            true => record.without_timing().print(format),
+            false => record.print(format),
+        }
+    }
+}
+
+fn print_pretty(report: &RunReport, no_timing: bool) {
+    if let Some(parse_time) = report.parse_time.filter(|_| !no_timing) {
+        println!("{} {}\n", "Parsed in".italic(), format_duration(parse_time));
+    }
+
+    for solution in &report.solutions {
+        println!(
+            "🌟 Part {} for {}:\n",
+            solution.part.as_str().cyan().bold(),
+            solution.name.cyan().bold(),
+        );
+
+        // The error itself is reported once the run is over, with the input as its source.
+        let Ok(answer) = &solution.answer else {
+            println!("{} {}\n", "Answer:".italic(), "failed".red().bold());
+            continue;
+        };
+        print!("{} {}", "Answer:".italic(), answer.as_str().green().bold());
+        match (solution.status(), &solution.expected) {
+            (Status::Correct, _) => print!(" ✅"),
+            (Status::Incorrect, Some(expected)) => {
+                print!(" ❌ {}", format!("(expected {})", expected).red())
+            }
+            _ => {}
+        }
+        if let Some(time) = solution.solve_time.filter(|_| !no_timing) {
+            print!(" {}", format!("({})", format_duration(time)).dimmed());
+        }
+        println!("\n");
+    }
+}
+
+/// The outcome of running one solution once, as other tools read it.
+///
+/// This is what `--output json` prints a line of per solution, so tools running the solutions can
+/// read the results back. With `--no-timing` the times are left out, as `null`, so the output is
+/// the same on every run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Part,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<String>,
+    pub answer: Option<String>,
+    pub status: Status,
+    /// The answer expected instead, only given when the answer is incorrect.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    pub parse_time_ns: Option<u64>,
+    pub solve_time_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(report: &RunReport, solution: &SolutionReport) -> Self {
+        let status = solution.status();
+
+        Record {
+            year: report.puzzle.map(|puzzle| puzzle.year),
+            day: report.puzzle.map(|puzzle| puzzle.day),
+            part: solution.part.clone(),
+            name: solution.name.clone(),
+            profile: report.profile.clone(),
+            example: report.example.clone(),
+            answer: solution.answer.as_ref().ok().map(ToString::to_string),
+            status,
+            expected: solution
+                .expected
+                .as_ref()
+                .filter(|_| status == Status::Incorrect)
+                .map(ToString::to_string),
+            parse_time_ns: report.parse_time.map(nanos),
+            solve_time_ns: solution.solve_time.map(nanos),
+            error: solution.answer.as_ref().err().map(ToString::to_string),
+        }
+    }
+
+    /// The record without how long parsing and solving took.
+    fn without_timing(self) -> Self {
+        Record {
+            parse_time_ns: None,
+            solve_time_ns: None,
+            ..self
+        }
+    }
+
+    /// Prints the record as a single line in the given format, which shouldn't be pretty.
+    fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Json => println!(
+                "{}",
+                serde_json::to_string(self).expect("Records serialize to JSON")
+            ),
+            _ => println!("{}", self.plain()),
+        }
+    }
+
+    fn plain(&self) -> String {
+        let mut line = match (&self.profile, &self.example) {
+            (_, Some(example)) => format!("{} part {} for {}: ", example, self.part, self.name),
+            (Some(profile), None) => format!("{} part {} for {}: ", profile, self.part, self.name),
+            (None, None) => format!("Part {} for {}: ", self.part, self.name),
+        };
+
+        let mut notes = vec![];
+        match (&self.answer, &self.error) {
+            (Some(answer), _) => line.push_str(answer),
+            (None, error) => line.push_str(&format!(
+                "failed, {}",
+                error.as_deref().unwrap_or("no answer")
+            )),
+        }
+        match (self.status, &self.expected) {
+            (Status::Correct, _) => notes.push("correct".to_string()),
+            (Status::Incorrect, Some(expected)) => notes.push(format!("expected {}", expected)),
+            _ => {}
+        }
+        if let Some(time) = self.solve_time_ns {
+            notes.push(format_duration(Duration::from_nanos(time)));
+        }
+
+        if !notes.is_empty() {
+            line.push_str(&format!(" ({})", notes.join(", ")));
+        }
+        line
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Answer;
+    use miette::{miette, Result};
+    use pretty_assertions::assert_eq;
+    use std::path::Path;
+
+    fn report(answer: Result<Answer>, solve_time: Option<Duration>, expected: i64) -> RunReport {
+        RunReport::new(
+            Path::new("inputs/2024/01.txt"),
+            Some(Duration::from_nanos(500)),
+            vec![SolutionReport {
+                part: Part::One,
+                name: "solution".to_string(),
+                answer,
+                solve_time,
+                expected: Some(Answer::new(expected)),
+            }],
+        )
+    }
+
+    fn record(report: &RunReport) -> Record {
+        Record::new(report, &report.solutions[0])
+    }
+
+    #[test]
+    fn test_json() {
+        let solved = report(Ok(Answer::new(12)), Some(Duration::from_micros(3)), 11);
+
+        assert_eq!(
+            serde_json::to_string(&record(&solved)).unwrap(),
+            r#"{"year":2024,"day":1,"part":"one","name":"solution","answer":"12","status":"incorrect","expected":"11","parse_time_ns":500,"solve_time_ns":3000,"error":null}"#
+        );
+
+        let failed = report(Err(miette!("Boom")), None, 11);
+        assert_eq!(
+            serde_json::to_string(&record(&failed)).unwrap(),
+            r#"{"year":2024,"day":1,"part":"one","name":"solution","answer":null,"status":"failed","parse_time_ns":500,"solve_time_ns":null,"error":"Boom"}"#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&record(&solved).without_timing()).unwrap(),
+            r#"{"year":2024,"day":1,"part":"one","name":"solution","answer":"12","status":"incorrect","expected":"11","parse_time_ns":null,"solve_time_ns":null,"error":null}"#
+        );
+
+        let line = serde_json::to_string(&record(&solved)).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Record>(&line).unwrap(),
+            record(&solved)
+        );
+    }
+
+    #[test]
+    fn test_plain() {
+        let solved = report(Ok(Answer::new(11)), Some(Duration::from_micros(3)), 11);
+        assert_eq!(
+            record(&solved).plain(),
+            "Part one for solution: 11 (correct, 3.00 µs)"
+        );
+        assert_eq!(
+            record(&solved).without_timing().plain(),
+            "Part one for solution: 11 (correct)"
+        );
+
+        let example = RunReport {
+            example: Some("example1".to_string()),
+            ..report(Ok(Answer::new(12)), Some(Duration::ZERO), 11)
+        };
+        assert_eq!(
+            record(&example).without_timing().plain(),
+            "example1 part one for solution: 12 (expected 11)"
+        );
+
+        let failed = report(Err(miette!("Boom")), None, 11);
+        assert_eq!(
+            record(&failed).plain(),
+            "Part one for solution: failed, Boom"
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Runs the solutions against the inputs of several accounts, to catch the ones they get wrong.
+//!
+//! Besides the day's own input, each profile has its input stored as `{day}/{profile}.txt` next
+//! to it, such as `inputs/2024/01/alice.txt`, with its answers recorded alongside.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use colored::*;
+use miette::{miette, Result};
+
+use crate::answers::AnswersFile;
+
+use super::{
+    format_duration, output, read_input,
+    report::{Prepared, RunReport, SolutionReport, Status},
+    table::markdown_table,
+    AocRunnerArgs, OutputFormat, Part, Registry, SolutionFn,
+};
+
+/// The name the day's own input goes by among the profiles.
+const DEFAULT_PROFILE: &str = "default";
+
+/// The input of the profile for the day whose own input is at `input_path`.
+pub(super) fn profile_path(input_path: &Path, profile: &str) -> PathBuf {
+    input_path
+        .with_extension("")
+        .join(format!("{}.txt", profile))
+}
+
+/// Every profile with an input for the day, starting with the day's own input when it exists.
+fn find(input_path: &Path) -> Vec<(String, PathBuf)> {
+    let mut profiles = vec![];
+    if input_path.is_file() {
+        profiles.push((DEFAULT_PROFILE.to_string(), input_path.to_path_buf()));
+    }
+
+    let Ok(entries) = fs::read_dir(input_path.with_extension("")) else {
+        return profiles;
+    };
+    let mut named = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .filter_map(|path| {
+            let profile = path.file_stem()?.to_str()?.to_string();
+            // Skips the examples and answers stored along with the inputs.
+            (!profile.contains('.')).then_some((profile, path))
+        })
+        .collect::<Vec<_>>();
+    named.sort();
+    profiles.extend(named);
+
+    profiles
+}
+
+/// Runs every selected solution against the input of every profile, failing if any of them
+/// doesn't match the answer accepted for that profile.
+pub(super) fn run(
+    registry: &Registry,
+    input_path: &Path,
+    solutions: &[(Part, String, SolutionFn)],
+    args: &AocRunnerArgs,
+) -> Result<()> {
+    let profiles = find(input_path);
+    if profiles.is_empty() {
+        return Err(miette!(
+            "No inputs found for {}, add profiles as `{}`",
+            input_path.display(),
+            profile_path(input_path, "name").display()
+        ));
+    }
+
+    let mut reports = vec![];
+    for (profile, path) in profiles {
+        let answers = AnswersFile::load(&path)?;
+        let prepared = read_input(&path)
+            .and_then(|raw| Prepared::new(registry, &path, raw, solutions.to_vec()));
+
+        let report = match prepared {
+            Ok(prepared) => prepared.run(|part| answers.get(part)),
+            // Every solution fails along with the input, so the other profiles still run.
+            Err(report) => {
+                let failed = solutions
+                    .iter()
+                    .map(|(part, name, _)| SolutionReport {
+                        part: part.clone(),
+                        name: name.clone(),
+                        answer: Err(miette!("{}", report)),
+                        solve_time: None,
+                        expected: answers.get(part),
+                    })
+                    .collect();
+                RunReport::new(&path, None, failed)
+            }
+        };
+        reports.push((profile, report));
+    }
+
+    if args.output == OutputFormat::Pretty {
+        for (part, name, _) in solutions {
+            println!(
+                "🌟 Part {} for {} across profiles:\n",
+                part.as_str().cyan().bold(),
+                name.cyan().bold()
+            );
+            let results = reports
+                .iter()
+                .flat_map(|(profile, report)| {
+                    report
+                        .solutions
+                        .iter()
+                        .filter(|solution| solution.part == *part && solution.name == *name)
+                        .map(move |solution| (profile.as_str(), solution))
+                })
+                .collect::<Vec<_>>();
+            println!("{}", results_table(&results, args.no_timing));
+        }
+    } else {
+        for (_, report) in &reports {
+            output::print_records(report, args.output, args.no_timing);
+        }
+    }
+
+    let wrong = reports
+        .iter()
+        .flat_map(|(profile, report)| {
+            report.failures().map(move |solution| {
+                format!("{} ({} part {})", profile, solution.name, solution.part)
+            })
+        })
+        .collect::<Vec<_>>();
+    if wrong.is_empty() {
+        Ok(())
+    } else {
+        Err(miette!(
+            "Wrong answers for the inputs of {}",
+            wrong.join(", ")
+        ))
+    }
+}
+
+fn results_table(results: &[(&str, &SolutionReport)], no_timing: bool) -> String {
+    let rows = results
+        .iter()
+        .map(|(profile, result)| {
+            let answer = match &result.answer {
+                Ok(answer) => answer.to_string(),
+                Err(report) => format!("failed, {}", report),
+            };
+            let verification = match (result.status(), &result.expected) {
+                (Status::Correct, _) => "✓".to_string(),
+                (Status::Incorrect, Some(expected)) => format!("✗ (expected {})", expected),
+                (Status::Failed, _) => "✗".to_string(),
+                _ => String::new(),
+            };
+
+            let mut row = vec![profile.to_string(), answer, verification];
+            if !no_timing {
+                row.push(result.solve_time.map(format_duration).unwrap_or_default());
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let header: &[&str] = if no_timing {
+        &["Profile", "Answer", "Accepted"]
+    } else {
+        &["Profile", "Answer", "Accepted", "Time"]
+    };
+
+    markdown_table(header, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Answer;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_profile_path() {
+        assert_eq!(
+            profile_path(Path::new("inputs/2024/01.txt"), "alice"),
+            PathBuf::from("inputs/2024/01/alice.txt")
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let input_path = PathBuf::from("test_profiles_input.txt");
+        let dir = PathBuf::from("test_profiles_input");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&input_path, "1").unwrap();
+        fs::write(dir.join("bob.txt"), "2").unwrap();
+        fs::write(dir.join("alice.txt"), "3").unwrap();
+        fs::write(dir.join("alice.answers.toml"), "").unwrap();
+        fs::write(dir.join("alice.example1.txt"), "").unwrap();
+
+        let profiles = find(&input_path)
+            .into_iter()
+            .map(|(profile, _)| profile)
+            .collect::<Vec<_>>();
+
+        assert_eq!(profiles, vec!["default", "alice", "bob"]);
+
+        // Cleanup
+        fs::remove_file(&input_path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_results_table() {
+        let result = |answer: Result<Answer>, expected: Option<i64>| SolutionReport {
+            part: Part::One,
+            name: "solution".to_string(),
+            answer,
+            solve_time: Some(Duration::ZERO),
+            expected: expected.map(Answer::new),
+        };
+        let results = [
+            ("alice", result(Ok(Answer::new(5)), Some(5))),
+            ("bob", result(Ok(Answer::new(7)), Some(6))),
+            ("carol", result(Ok(Answer::new(8)), None)),
+            ("dave", result(Err(miette!("Boom")), None)),
+        ];
+
+        let expected = "\
+| Profile |       Answer |       Accepted |
+| :------ | -----------: | -------------: |
+| alice   |            5 |              ✓ |
+| bob     |            7 | ✗ (expected 6) |
+| carol   |            8 |                |
+| dave    | failed, Boom |              ✗ |
+";
+        let rows = results
+            .iter()
+            .map(|(profile, result)| (*profile, result))
+            .collect::<Vec<_>>();
+        let table = results_table(&rows, true);
+        assert_eq!(table, expected);
+    }
+}
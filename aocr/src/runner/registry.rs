@@ -0,0 +1,507 @@
+//! The solutions and generator a binary is built with.
+//!
+//! `#[aoc]` and `#[aoc_generator]` add their functions to distributed slices that the linker
+//! gathers into one place, so nothing runs before `main` and nothing is mutated afterwards.
+//!
+//! Each function belongs to the puzzle given to the attribute, or else to the one inferred from
+//! where it is built: the year from a package named like `aoc_2024`, and the day from the
+//! `dayNN` module it is defined in or a binary named like `01`. Functions without a day belong
+//! to every day, so a binary may hold a single day or all of them.
+
+use linkme::distributed_slice;
+use miette::{miette, Result};
+
+use super::{FunctionRegistry, GeneratorFn, Part, PuzzleId, SolutionFn};
+
+/// A solution function, as registered by `#[aoc]`.
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub package: &'static str,
+    pub bin: Option<&'static str>,
+    pub module: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    /// The doc comment of the function, without the leading `///`.
+    pub doc: &'static str,
+    pub part: Part,
+    pub name: &'static str,
+    pub func: SolutionFn,
+}
+
+/// A generator function, as registered by `#[aoc_generator]`.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub package: &'static str,
+    pub bin: Option<&'static str>,
+    pub module: &'static str,
+    pub func: GeneratorFn,
+}
+
+/// A solution function in a registry, along with where it is defined when that is known.
+#[derive(Debug, Clone, Copy)]
+pub struct Registered {
+    pub func: SolutionFn,
+    /// The file and line the function is defined at.
+    pub location: Option<(&'static str, u32)>,
+    pub doc: &'static str,
+}
+
+impl Registered {
+    fn new(func: SolutionFn) -> Self {
+        Registered {
+            func,
+            location: None,
+            doc: "",
+        }
+    }
+}
+
+/// What a solution is registered and looked up under.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SolutionKey {
+    /// The puzzle the solution belongs to, or `None` when its year or day isn't known.
+    pub puzzle: Option<PuzzleId>,
+    pub part: Part,
+    pub name: String,
+}
+
+impl SolutionKey {
+    pub fn new(puzzle: Option<PuzzleId>, part: Part, name: &str) -> Self {
+        SolutionKey {
+            puzzle,
+            part,
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Every solution registered with `#[aoc]` in the binary.
+#[distributed_slice]
+pub static SOLUTIONS: [Solution];
+
+/// Every generator registered with `#[aoc_generator]` in the binary, at most one per day.
+#[distributed_slice]
+pub static GENERATORS: [Generator];
+
+impl Solution {
+    pub fn year(&self) -> Option<u16> {
+        self.year.or_else(|| year_from_package(self.package))
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+            .or_else(|| day_from_module(self.module))
+            .or_else(|| self.bin?.parse().ok())
+    }
+
+    pub fn puzzle(&self) -> Option<PuzzleId> {
+        Some(PuzzleId::new(self.year()?, self.day()?))
+    }
+
+    pub fn key(&self) -> SolutionKey {
+        SolutionKey::new(self.puzzle(), self.part.clone(), self.name)
+    }
+
+    fn registered(&self) -> Registered {
+        Registered {
+            func: self.func,
+            location: Some((self.file, self.line)),
+            doc: self.doc,
+        }
+    }
+}
+
+impl Generator {
+    pub fn day(&self) -> Option<u8> {
+        self.day
+            .or_else(|| day_from_module(self.module))
+            .or_else(|| self.bin?.parse().ok())
+    }
+}
+
+/// The year of a package named after it, such as `aoc_2024`.
+fn year_from_package(package: &str) -> Option<u16> {
+    package.rsplit(['_', '-']).next()?.parse().ok()
+}
+
+/// The day of a module path with a `dayNN` segment, such as `aoc_2024::day01::part_one`.
+fn day_from_module(module: &str) -> Option<u8> {
+    module
+        .split("::")
+        .find_map(|segment| segment.strip_prefix("day")?.parse().ok())
+}
+
+/// Whether a function of `func_day` runs on `day`, where `None` stands for any day.
+fn runs_on(func_day: Option<u8>, day: Option<u8>) -> bool {
+    match (func_day, day) {
+        (Some(func_day), Some(day)) => func_day == day,
+        _ => true,
+    }
+}
+
+/// The functions the runner picks from, keyed by puzzle, part and name.
+#[derive(Debug, Default, Clone)]
+pub struct Registry {
+    functions: FunctionRegistry,
+    generator: Option<GeneratorFn>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the functions registered with `#[aoc]` and `#[aoc_generator]` in the binary.
+    pub fn linked() -> Result<Self> {
+        Self::from_slices(&SOLUTIONS, &GENERATORS, None)
+    }
+
+    /// Collects the functions registered in the binary for the given day.
+    pub fn linked_for_day(day: u8) -> Result<Self> {
+        Self::from_slices(&SOLUTIONS, &GENERATORS, Some(day))
+    }
+
+    /// The days the functions registered in the binary belong to, in order.
+    pub fn linked_days() -> Vec<u8> {
+        let mut days = SOLUTIONS
+            .iter()
+            .filter_map(Solution::day)
+            .collect::<Vec<_>>();
+        days.sort_unstable();
+        days.dedup();
+
+        days
+    }
+
+    pub(super) fn from_slices(
+        solutions: &[Solution],
+        generators: &[Generator],
+        day: Option<u8>,
+    ) -> Result<Self> {
+        let mut registry = Registry::new();
+        let solutions = solutions
+            .iter()
+            .filter(|s| runs_on(s.day(), day))
+            .collect::<Vec<_>>();
+
+        // Functions of any day clash with the ones of the day they run on.
+        let clash = |a: &Solution, b: &Solution| {
+            a.part == b.part && a.name == b.name && (day.is_some() || a.puzzle() == b.puzzle())
+        };
+        let mut conflicts = vec![];
+        for (i, solution) in solutions.iter().enumerate() {
+            registry
+                .functions
+                .insert(solution.key(), solution.registered());
+
+            // Each conflict is reported once, from the first function involved.
+            if solutions[..i].iter().any(|other| clash(other, solution)) {
+                continue;
+            }
+            let modules = solutions[i..]
+                .iter()
+                .filter(|other| clash(other, solution))
+                .map(|other| other.module)
+                .collect::<Vec<_>>();
+            if modules.len() > 1 {
+                conflicts.push(format!(
+                    "part {} named {} in {}",
+                    solution.part,
+                    solution.name,
+                    modules.join(", ")
+                ));
+            }
+        }
+        if !conflicts.is_empty() {
+            return Err(miette!(
+                "Multiple functions registered for {}",
+                conflicts.join("; ")
+            ));
+        }
+
+        let generators = generators
+            .iter()
+            .filter(|g| runs_on(g.day(), day))
+            .collect::<Vec<_>>();
+        match generators.as_slice() {
+            [] => {}
+            [generator] => registry.generator = Some(generator.func),
+            _ => {
+                let modules = generators.iter().map(|g| g.module).collect::<Vec<_>>();
+                return Err(miette!(
+                    "Multiple generators registered in {}",
+                    modules.join(", ")
+                ));
+            }
+        }
+
+        Ok(registry)
+    }
+
+    /// Adds a solution function for no puzzle in particular, replacing any registered with the
+    /// same part and name.
+    pub fn with_solution(self, part: Part, name: &str, func: SolutionFn) -> Self {
+        self.with_key(SolutionKey::new(None, part, name), func)
+    }
+
+    /// Adds a solution function under the given key, replacing any registered with the same one.
+    pub fn with_key(mut self, key: SolutionKey, func: SolutionFn) -> Self {
+        self.functions.insert(key, Registered::new(func));
+        self
+    }
+
+    pub fn with_generator(mut self, func: GeneratorFn) -> Self {
+        self.generator = Some(func);
+        self
+    }
+
+    /// The function registered with the exact key.
+    pub fn get_key(&self, key: &SolutionKey) -> Option<SolutionFn> {
+        self.functions.get(key).map(|registered| registered.func)
+    }
+
+    /// The function registered for the part under the exact name, whichever puzzle it belongs to.
+    pub fn get(&self, part: &Part, name: &str) -> Option<SolutionFn> {
+        self.functions
+            .iter()
+            .find(|(key, _)| key.part == *part && key.name == name)
+            .map(|(_, registered)| registered.func)
+    }
+
+    /// The keys of every registered function, ordered by puzzle, part and name.
+    pub fn keys(&self) -> impl Iterator<Item = &SolutionKey> {
+        self.functions.keys()
+    }
+
+    /// Every registered function with its key, ordered by puzzle, part and name.
+    pub fn iter(&self) -> impl Iterator<Item = (&SolutionKey, &Registered)> {
+        self.functions.iter()
+    }
+
+    /// The puzzles the registered functions belong to, in order.
+    pub fn puzzles(&self) -> Vec<PuzzleId> {
+        let mut puzzles = self.keys().filter_map(|key| key.puzzle).collect::<Vec<_>>();
+        puzzles.dedup();
+
+        puzzles
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    pub(super) fn functions(&self) -> &FunctionRegistry {
+        &self.functions
+    }
+
+    pub(super) fn generator(&self) -> Option<GeneratorFn> {
+        self.generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{Answer, Input};
+    use pretty_assertions::assert_eq;
+    use std::any::Any;
+
+    fn solution(_input: &Input) -> Result<Answer> {
+        Ok(Answer::new(1))
+    }
+
+    fn parse(input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(input.len()))
+    }
+
+    fn registered(module: &'static str, part: Part) -> Solution {
+        Solution {
+            year: None,
+            day: None,
+            package: "aocr",
+            bin: None,
+            module,
+            file: "src/main.rs",
+            line: 1,
+            doc: "",
+            part,
+            name: "solution",
+            func: solution,
+        }
+    }
+
+    fn generator(module: &'static str) -> Generator {
+        Generator {
+            year: None,
+            day: None,
+            package: "aocr",
+            bin: None,
+            module,
+            func: parse,
+        }
+    }
+
+    #[test]
+    fn test_from_slices() {
+        let solutions = [registered("_01", Part::One), registered("_01", Part::Two)];
+
+        let registry = Registry::from_slices(&solutions, &[generator("_01")], None).unwrap();
+
+        assert!(registry.get(&Part::One, "solution").is_some());
+        assert!(registry.get(&Part::Two, "solution").is_some());
+        assert!(registry.get(&Part::Two, "other").is_none());
+        assert!(registry.generator().is_some());
+    }
+
+    #[test]
+    fn test_from_slices_for_day() {
+        let solutions = [
+            registered("aoc_2024::day01::part_one", Part::One),
+            registered("aoc_2024::day02", Part::One),
+            Solution {
+                day: Some(3),
+                ..registered("aoc_2024::day02", Part::Two)
+            },
+        ];
+        let generators = [generator("aoc_2024::day02")];
+
+        let day_one = Registry::from_slices(&solutions, &generators, Some(1)).unwrap();
+        assert!(day_one.get(&Part::One, "solution").is_some());
+        assert!(day_one.get(&Part::Two, "solution").is_none());
+        assert!(day_one.generator().is_none());
+
+        let day_two = Registry::from_slices(&solutions, &generators, Some(2)).unwrap();
+        assert!(day_two.get(&Part::One, "solution").is_some());
+        assert!(day_two.get(&Part::Two, "solution").is_none());
+        assert!(day_two.generator().is_some());
+
+        let day_three = Registry::from_slices(&solutions, &generators, Some(3)).unwrap();
+        assert!(day_three.get(&Part::One, "solution").is_none());
+        assert!(day_three.get(&Part::Two, "solution").is_some());
+    }
+
+    #[test]
+    fn test_from_slices_with_duplicates() {
+        let solutions = [
+            registered("aoc_2024::day01::part_one", Part::One),
+            registered("aoc_2024::day01::alternative", Part::One),
+            registered("aoc_2024::day01::part_two", Part::Two),
+            registered("aoc_2024::day01::alternative", Part::Two),
+        ];
+
+        let error = Registry::from_slices(&solutions, &[], None).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Multiple functions registered for \
+            part one named solution in aoc_2024::day01::part_one, aoc_2024::day01::alternative; \
+            part two named solution in aoc_2024::day01::part_two, aoc_2024::day01::alternative"
+        );
+
+        let error =
+            Registry::from_slices(&[], &[generator("_01"), generator("_02")], None).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Multiple generators registered in _01, _02"
+        );
+    }
+
+    #[test]
+    fn test_from_slices_with_duplicates_of_any_day() {
+        let solutions = [
+            registered("aoc_2024::shared", Part::One),
+            registered("aoc_2024::day01", Part::One),
+            registered("aoc_2024::day02", Part::One),
+        ];
+
+        assert!(Registry::from_slices(&solutions, &[], Some(3)).is_ok());
+        let error = Registry::from_slices(&solutions, &[], Some(1)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Multiple functions registered for part one named solution in aoc_2024::shared, aoc_2024::day01"
+        );
+    }
+
+    #[test]
+    fn test_from_slices_for_several_days() {
+        let solutions = [
+            Solution {
+                package: "aoc_2024",
+                ..registered("aoc_2024::day01", Part::One)
+            },
+            Solution {
+                package: "aoc_2024",
+                ..registered("aoc_2024::day02", Part::One)
+            },
+        ];
+
+        let registry = Registry::from_slices(&solutions, &[], None).unwrap();
+
+        assert_eq!(
+            registry.puzzles(),
+            vec![PuzzleId::new(2024, 1), PuzzleId::new(2024, 2)]
+        );
+        let key = SolutionKey::new(Some(PuzzleId::new(2024, 2)), Part::One, "solution");
+        assert!(registry.get_key(&key).is_some());
+        assert!(registry
+            .get_key(&SolutionKey::new(None, Part::One, "solution"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_solution_puzzle() {
+        let solution = registered("aoc_2024::day03", Part::One);
+        assert_eq!(solution.puzzle(), None);
+        assert_eq!(solution.day(), Some(3));
+
+        let packaged = Solution {
+            package: "aoc_2024",
+            ..solution.clone()
+        };
+        assert_eq!(packaged.puzzle(), Some(PuzzleId::new(2024, 3)));
+
+        let explicit = Solution {
+            year: Some(2015),
+            day: Some(7),
+            ..packaged.clone()
+        };
+        assert_eq!(explicit.puzzle(), Some(PuzzleId::new(2015, 7)));
+
+        let binary = Solution {
+            package: "aoc_2015",
+            bin: Some("01"),
+            ..registered("_01::solutions", Part::Two)
+        };
+        assert_eq!(
+            binary.key(),
+            SolutionKey::new(Some(PuzzleId::new(2015, 1)), Part::Two, "solution")
+        );
+    }
+
+    #[test]
+    fn test_year_from_package() {
+        assert_eq!(year_from_package("aoc_2024"), Some(2024));
+        assert_eq!(year_from_package("advent-2015"), Some(2015));
+        assert_eq!(year_from_package("aocr"), None);
+    }
+
+    #[test]
+    fn test_day_from_module() {
+        assert_eq!(day_from_module("aoc_2024::day01::part_one"), Some(1));
+        assert_eq!(day_from_module("aoc_2024::day25"), Some(25));
+        assert_eq!(day_from_module("_01::solutions"), None);
+        assert_eq!(day_from_module("aoc_2024::daydream"), None);
+    }
+
+    #[test]
+    fn test_linked_registry_is_empty_without_solutions() {
+        let registry = Registry::linked().unwrap();
+
+        assert!(registry.is_empty());
+        assert!(registry.generator().is_none());
+        assert!(Registry::linked_days().is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+//! Runs the solutions into reports, leaving how they are presented to the caller.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use miette::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{generate_input, solve, Answer, Input, Part, Registry, SolutionFn};
+
+/// A puzzle, identified by its year and day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl PuzzleId {
+    pub fn new(year: u16, day: u8) -> Self {
+        PuzzleId { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{:02}", self.year, self.day)
+    }
+}
+
+/// How an answer compares to the one it is checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Correct,
+    Incorrect,
+    Unverified,
+    Failed,
+}
+
+/// The outcome of running one solution.
+#[derive(Debug)]
+pub struct SolutionReport {
+    pub part: Part,
+    pub name: String,
+    /// The answer, or the error the solution failed with, which carries the input as its source.
+    pub answer: Result<Answer>,
+    pub solve_time: Option<Duration>,
+    /// The answer the solution is checked against, if any is known.
+    pub expected: Option<Answer>,
+}
+
+impl SolutionReport {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) => Status::Failed,
+            (Ok(answer), Some(expected)) if answer == expected => Status::Correct,
+            (Ok(_), Some(_)) => Status::Incorrect,
+            (Ok(_), None) => Status::Unverified,
+        }
+    }
+}
+
+/// The outcome of running the selected solutions once on an input.
+#[derive(Debug)]
+pub struct RunReport {
+    /// The puzzle of the input, when it is stored as `inputs/{year}/{day}.txt`.
+    pub puzzle: Option<PuzzleId>,
+    /// The profile the input belongs to, when it is stored as `{day}/{profile}.txt`.
+    pub profile: Option<String>,
+    /// The name of the example the solutions ran on, rather than the input.
+    pub example: Option<String>,
+    pub input_path: PathBuf,
+    /// How long the generator took, when one is registered.
+    pub parse_time: Option<Duration>,
+    pub solutions: Vec<SolutionReport>,
+}
+
+impl RunReport {
+    pub fn new(
+        input_path: &Path,
+        parse_time: Option<Duration>,
+        solutions: Vec<SolutionReport>,
+    ) -> Self {
+        let (puzzle, profile) = puzzle_of(input_path);
+
+        RunReport {
+            puzzle,
+            profile,
+            example: None,
+            input_path: input_path.to_path_buf(),
+            parse_time,
+            solutions,
+        }
+    }
+
+    /// The solutions that failed or whose answers don't match the expected ones.
+    pub fn failures(&self) -> impl Iterator<Item = &SolutionReport> {
+        self.solutions
+            .iter()
+            .filter(|solution| matches!(solution.status(), Status::Failed | Status::Incorrect))
+    }
+
+    /// Takes the error of the first solution that failed, if any.
+    pub fn into_error(self) -> Option<miette::Report> {
+        self.solutions
+            .into_iter()
+            .find_map(|solution| solution.answer.err())
+    }
+}
+
+/// An input ready for the solutions to run on, its generator already run over it.
+pub(super) struct Prepared {
+    pub input_path: PathBuf,
+    pub input: Input,
+    pub parse_time: Option<Duration>,
+    pub solutions: Vec<(Part, String, SolutionFn)>,
+}
+
+impl Prepared {
+    pub fn new(
+        registry: &Registry,
+        input_path: &Path,
+        raw: String,
+        solutions: Vec<(Part, String, SolutionFn)>,
+    ) -> Result<Self> {
+        let (input, parse_time) = generate_input(registry, raw, input_path)?;
+
+        Ok(Prepared {
+            input_path: input_path.to_path_buf(),
+            input,
+            parse_time,
+            solutions,
+        })
+    }
+
+    /// Runs every solution once, checking each against the answer `expected` gives for its part.
+    pub fn run(&self, expected: impl Fn(&Part) -> Option<Answer>) -> RunReport {
+        let solutions = self
+            .solutions
+            .iter()
+            .map(|(part, name, func)| {
+                let solved = solve(*func, &self.input, &self.input_path);
+
+                SolutionReport {
+                    part: part.clone(),
+                    name: name.clone(),
+                    solve_time: solved.as_ref().ok().map(|(_, time)| *time),
+                    answer: solved.map(|(answer, _)| answer),
+                    expected: expected(part),
+                }
+            })
+            .collect();
+
+        RunReport::new(&self.input_path, self.parse_time, solutions)
+    }
+}
+
+/// The puzzle and profile of an input stored as `inputs/{year}/{day}.txt`, or as
+/// `inputs/{year}/{day}/{profile}.txt` for the input of a profile.
+///
+/// Anything after the first dot of the file name is ignored, so examples such as
+/// `01.example1.txt` belong to their day too.
+fn puzzle_of(input_path: &Path) -> (Option<PuzzleId>, Option<String>) {
+    let file_name = input_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split('.').next());
+    let mut dirs = input_path
+        .parent()
+        .into_iter()
+        .flat_map(|dir| dir.iter().rev())
+        .map(|name| name.to_str());
+
+    let day = |name: Option<&str>| name.and_then(|name| name.parse::<u8>().ok());
+    let year = |name: Option<Option<&str>>| name.flatten().and_then(|name| name.parse().ok());
+
+    match day(file_name) {
+        Some(day) => (year(dirs.next()).map(|year| PuzzleId::new(year, day)), None),
+        None => {
+            let profile = file_name.map(ToString::to_string);
+            match (day(dirs.next().flatten()), year(dirs.next())) {
+                (Some(day), Some(year)) => (Some(PuzzleId::new(year, day)), profile),
+                _ => (None, None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::miette;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_puzzle_of() {
+        let puzzle = Some(PuzzleId::new(2024, 1));
+
+        assert_eq!(puzzle_of(Path::new("inputs/2024/01.txt")), (puzzle, None));
+        assert_eq!(
+            puzzle_of(Path::new("inputs/2024/01.example1.txt")),
+            (puzzle, None)
+        );
+        assert_eq!(
+            puzzle_of(Path::new("inputs/2024/01/alice.txt")),
+            (puzzle, Some("alice".to_string()))
+        );
+        assert_eq!(puzzle_of(Path::new("my_input.txt")), (None, None));
+        assert_eq!(puzzle_of(Path::new("-")), (None, None));
+    }
+
+    #[test]
+    fn test_status() {
+        let solution = |answer: Result<Answer>, expected: Option<i64>| SolutionReport {
+            part: Part::One,
+            name: "solution".to_string(),
+            answer,
+            solve_time: None,
+            expected: expected.map(Answer::new),
+        };
+
+        assert_eq!(
+            solution(Ok(Answer::new(1)), Some(1)).status(),
+            Status::Correct
+        );
+        assert_eq!(
+            solution(Ok(Answer::new(1)), Some(2)).status(),
+            Status::Incorrect
+        );
+        assert_eq!(
+            solution(Ok(Answer::new(1)), None).status(),
+            Status::Unverified
+        );
+        assert_eq!(
+            solution(Err(miette!("Boom")), Some(1)).status(),
+            Status::Failed
+        );
+    }
+
+    #[test]
+    fn test_puzzle_id_display() {
+        assert_eq!(PuzzleId::new(2024, 1).to_string(), "2024/01");
+    }
+}
@@ -0,0 +1,69 @@
+//! Markdown tables with aligned columns, readable both in a terminal and once rendered.
+
+/// Renders a Markdown table, left-aligning the first column and right-aligning the others.
+pub fn markdown_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| cell.chars().count())
+                .chain([title.chars().count(), 3])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let render_row = |cells: Vec<String>| {
+        let cells = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| match i {
+                0 => format!("{:<width$}", cell),
+                _ => format!("{:>width$}", cell),
+            })
+            .collect::<Vec<_>>();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let separator = widths
+        .iter()
+        .enumerate()
+        .map(|(i, width)| match i {
+            0 => format!(":{}", "-".repeat(width - 1)),
+            _ => format!("{}:", "-".repeat(width - 1)),
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = vec![
+        render_row(header.iter().map(ToString::to_string).collect()),
+        format!("| {} |", separator.join(" | ")),
+    ];
+    lines.extend(rows.iter().cloned().map(render_row));
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_markdown_table() {
+        let rows = vec![
+            vec!["`solution`".to_string(), "12.35 µs".to_string()],
+            vec!["`nom`".to_string(), "9 ns".to_string()],
+        ];
+
+        let expected = "\
+| Solution   |     Mean |
+| :--------- | -------: |
+| `solution` | 12.35 µs |
+| `nom`      |     9 ns |
+";
+        assert_eq!(markdown_table(&["Solution", "Mean"], &rows), expected);
+    }
+}
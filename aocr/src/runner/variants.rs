@@ -0,0 +1,194 @@
+//! Side by side comparison of every named variant of a part.
+
+use colored::*;
+use miette::{miette, Result};
+
+use crate::answers::AnswersFile;
+
+use super::{
+    copy_to_clipboard, format_duration, output,
+    report::{Prepared, SolutionReport, Status},
+    table::markdown_table,
+    Answer, AocRunnerArgs, OutputFormat,
+};
+
+/// Runs every variant, prints a comparison table per part and fails if any of them disagree.
+///
+/// Variants are checked against the accepted answer when there is one, and against the answer
+/// most of them agree on otherwise.
+pub(super) fn compare(
+    prepared: &Prepared,
+    answers: &AnswersFile,
+    args: &AocRunnerArgs,
+) -> Result<()> {
+    let mut report = prepared.run(|part| answers.get(part));
+
+    let mut parts = report
+        .solutions
+        .iter()
+        .map(|solution| solution.part.clone())
+        .collect::<Vec<_>>();
+    parts.dedup();
+    for part in &parts {
+        if answers.get(part).is_some() {
+            continue;
+        }
+        let majority =
+            majority_answer(report.solutions.iter().filter(|s| s.part == *part)).cloned();
+        for solution in report.solutions.iter_mut().filter(|s| s.part == *part) {
+            solution.expected = majority.clone();
+        }
+    }
+
+    if args.output == OutputFormat::Pretty {
+        for part in &parts {
+            let results = report
+                .solutions
+                .iter()
+                .filter(|solution| solution.part == *part)
+                .collect::<Vec<_>>();
+            println!("🌟 Part {} variants:\n", part.as_str().cyan().bold());
+            println!("{}", results_table(&results, args.no_timing));
+
+            for result in results
+                .iter()
+                .filter(|result| result.status() != Status::Correct)
+            {
+                let (Ok(answer), Some(expected)) = (&result.answer, &result.expected) else {
+                    println!("❌ {} failed", result.name.red().bold());
+                    continue;
+                };
+                println!(
+                    "❌ {} answered {} instead of {}",
+                    result.name.red().bold(),
+                    answer.as_str().red(),
+                    expected.as_str().green(),
+                );
+            }
+        }
+    } else {
+        output::print_records(&report, args.output, args.no_timing);
+    }
+
+    let output = report
+        .solutions
+        .last()
+        .and_then(|solution| solution.expected.as_ref());
+    if let Some(output) = output.filter(|_| args.output != OutputFormat::Json) {
+        copy_to_clipboard(output);
+    }
+
+    let disagreements = report
+        .failures()
+        .map(|solution| format!("{} (part {})", solution.name, solution.part))
+        .collect::<Vec<_>>();
+    if let Some(error) = report.into_error() {
+        return Err(error);
+    }
+
+    if disagreements.is_empty() {
+        Ok(())
+    } else {
+        Err(miette!(
+            "Variants disagree with the expected answer: {}",
+            disagreements.join(", ")
+        ))
+    }
+}
+
+/// The answer given by most variants, preferring the first one seen on ties.
+fn majority_answer<'a>(results: impl Iterator<Item = &'a SolutionReport>) -> Option<&'a Answer> {
+    let answers = results
+        .filter_map(|result| result.answer.as_ref().ok())
+        .collect::<Vec<_>>();
+    let count = |answer: &Answer| answers.iter().filter(|other| **other == answer).count();
+
+    answers
+        .iter()
+        .rev()
+        .max_by_key(|answer| count(answer))
+        .copied()
+}
+
+fn results_table(results: &[&SolutionReport], no_timing: bool) -> String {
+    let fastest = results.iter().filter_map(|result| result.solve_time).min();
+
+    let rows = results
+        .iter()
+        .map(|result| {
+            let agrees = if result.status() == Status::Correct {
+                "✓"
+            } else {
+                "✗"
+            };
+            let answer = match &result.answer {
+                Ok(answer) => answer.to_string(),
+                Err(_) => "failed".to_string(),
+            };
+            let mut row = vec![format!("`{}`", result.name), answer, agrees.to_string()];
+            if !no_timing {
+                let time = result.solve_time.unwrap_or_default();
+                let relative = match fastest.map(|fastest| fastest.as_nanos()) {
+                    Some(0) | None => 1.0,
+                    Some(fastest) => time.as_nanos() as f64 / fastest as f64,
+                };
+                row.push(result.solve_time.map(format_duration).unwrap_or_default());
+                row.push(format!("{:.2}", relative));
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let header: &[&str] = if no_timing {
+        &["Solution", "Answer", "Agrees"]
+    } else {
+        &["Solution", "Answer", "Agrees", "Time", "Relative"]
+    };
+
+    markdown_table(header, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Part;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    fn result(name: &str, answer: i64) -> SolutionReport {
+        SolutionReport {
+            part: Part::One,
+            name: name.to_string(),
+            answer: Ok(Answer::new(answer)),
+            solve_time: Some(Duration::from_micros(1)),
+            expected: Some(Answer::new(5)),
+        }
+    }
+
+    #[test]
+    fn test_majority_answer() {
+        let results = [result("a", 1), result("b", 2), result("c", 2)];
+        assert_eq!(majority_answer(results.iter()), Some(&Answer::new(2)));
+    }
+
+    #[test]
+    fn test_majority_answer_tie_prefers_first() {
+        let results = [result("a", 1), result("b", 2)];
+        assert_eq!(majority_answer(results.iter()), Some(&Answer::new(1)));
+    }
+
+    #[test]
+    fn test_results_table_flags_disagreement() {
+        let results = [result("closures", 5), result("for_loop", 7)];
+
+        let table = results_table(&results.iter().collect::<Vec<_>>(), true);
+
+        let expected = "\
+| Solution   | Answer | Agrees |
+| :--------- | -----: | -----: |
+| `closures` |      5 |      ✓ |
+| `for_loop` |      7 |      ✗ |
+";
+        assert_eq!(table, expected);
+    }
+}
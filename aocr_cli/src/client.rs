@@ -0,0 +1,366 @@
+use std::{env, fmt, fs, path::Path, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aocr::runner::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "aocr-cli/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/hvpaiva/aoc_rust)"
+);
+
+/// The website's verdict on a submitted answer.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too soon after the previous attempt, nothing was checked.
+    #[serde(skip)]
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part isn't open, either because it's already solved or part one isn't yet.
+    #[serde(skip)]
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Parses the page the website answers a submission with.
+    fn from_response(html: &str) -> Result<Self> {
+        if html.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Ok(Verdict::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Ok(Verdict::TooLow)
+            } else {
+                Ok(Verdict::Incorrect)
+            }
+        } else if html.contains("You gave an answer too recently") {
+            Ok(Verdict::RateLimited {
+                wait: parse_wait(html).unwrap_or_default(),
+            })
+        } else if html.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            bail!("Unrecognized response to the submission")
+        }
+    }
+
+    /// Whether the answer was checked and found wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::RateLimited { wait } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Verdict::WrongLevel => write!(f, "not the right level"),
+        }
+    }
+}
+
+/// Parses the wait time out of "You have 4m 12s left to wait".
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    html[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// The address of the Advent of Code website, `AOC_BASE_URL` when set.
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL")
+        .unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Client for the Advent of Code website.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable, or from the first
+/// line of `<config dir>/aocr/session` or `~/.adventofcode.session`. The base URL defaults to
+/// the real website and can be pointed elsewhere with `AOC_BASE_URL`.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        AocClient {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn from_env() -> Result<Self> {
+        Ok(Self::new(base_url(), Self::session()?))
+    }
+
+    /// Downloads the input of the given day to `path`, unless it was already downloaded.
+    ///
+    /// Returns whether a download happened.
+    pub fn download_input(&self, year: u16, day: u8, path: &Path) -> Result<bool> {
+        if path.exists() {
+            return Ok(false);
+        }
+
+        let input = self.get(&format!("{}/day/{}/input", year, day))?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, input).with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(true)
+    }
+
+    /// Fetches the puzzle page of the given day, which includes part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("{}/day/{}", year, day))
+    }
+
+    /// Submits the answer for the given part and returns the website's verdict.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: &Part,
+        answer: &Answer,
+    ) -> Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[
+                ("level", &part.level().to_string()),
+                ("answer", answer.as_str()),
+            ]);
+
+        Verdict::from_response(&Self::body(&url, response)?)
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}/{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        Self::body(&url, response)
+    }
+
+    fn body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => {
+                bail!("The session cookie is invalid or expired")
+            }
+            Err(ureq::Error::Status(404, _)) => {
+                bail!("{} was not found, is the puzzle unlocked yet?", url)
+            }
+            Err(ureq::Error::Status(status, _)) => bail!("{} answered with {}", url, status),
+            Err(e) => Err(anyhow!(e)),
+        }
+    }
+
+    fn session() -> Result<String> {
+        if let Ok(session) = env::var("AOC_SESSION") {
+            return Ok(session.trim().to_string());
+        }
+
+        let session_files = [
+            dirs::config_dir().map(|dir| dir.join("aocr").join("session")),
+            dirs::home_dir().map(|dir| dir.join(".adventofcode.session")),
+        ];
+
+        session_files
+            .into_iter()
+            .flatten()
+            .find_map(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| contents.lines().next().map(|line| line.trim().to_string()))
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| anyhow!("No session cookie found, set AOC_SESSION to provide one"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves a single request with the given status and body, returning the request.
+    fn mock_server(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length: ") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join(format!("aocr-client-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_download_input() {
+        let (base_url, server) = mock_server(200, "3   4\n4   3\n");
+        let client = AocClient::new(base_url, "secret");
+        let path = temp_path("2024/01.txt");
+
+        assert!(client.download_input(2024, 1, &path).unwrap());
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=secret"));
+        assert!(request.contains(&format!("User-Agent: {}", USER_AGENT)));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        // Cached: the server is gone, so this would fail if it downloaded again.
+        assert!(!client.download_input(2024, 1, &path).unwrap());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_download_input_with_invalid_session() {
+        let (base_url, server) = mock_server(400, "");
+        let client = AocClient::new(base_url, "expired");
+        let path = temp_path("2024/02.txt");
+
+        let error = client.download_input(2024, 2, &path).unwrap_err();
+
+        server.join().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "The session cookie is invalid or expired"
+        );
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = mock_server(
+            200,
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+        );
+        let client = AocClient::new(base_url, "secret");
+
+        let verdict = client
+            .submit_answer(2024, 1, &Part::Two, &Answer::new(31))
+            .unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1"));
+        assert!(request.contains("Cookie: session=secret"));
+        assert!(request.ends_with("level=2&answer=31"));
+        assert_eq!(verdict, Verdict::Correct);
+    }
+
+    #[rstest]
+    #[case(
+        "That's not the right answer; your answer is too high.",
+        Verdict::TooHigh
+    )]
+    #[case(
+        "That's not the right answer; your answer is too low.",
+        Verdict::TooLow
+    )]
+    #[case(
+        "That's not the right answer. If you're stuck, ...",
+        Verdict::Incorrect
+    )]
+    #[case(
+        "You gave an answer too recently. You have 39s left to wait.",
+        Verdict::RateLimited { wait: Duration::from_secs(39) }
+    )]
+    #[case(
+        "You gave an answer too recently. You have 4m 12s left to wait.",
+        Verdict::RateLimited { wait: Duration::from_secs(252) }
+    )]
+    #[case("You don't seem to be solving the right level.", Verdict::WrongLevel)]
+    fn test_verdict_from_response(#[case] html: &str, #[case] expected: Verdict) {
+        assert_eq!(Verdict::from_response(html).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_verdict_from_unknown_response() {
+        assert!(Verdict::from_response("<html></html>").is_err());
+    }
+}
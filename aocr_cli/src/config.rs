@@ -1,14 +1,76 @@
-use std::{env::current_dir, fs, path::PathBuf};
+use std::{
+    env::current_dir,
+    fmt, fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use anyhow::Result;
+use aocr::runner::{Answer, Part};
 use serde::{Deserialize, Serialize};
 
+use crate::client::Verdict;
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct StateFile {
     pub current_year: Option<u16>,
     pub current_day: Option<u8>,
     initialized_years: Vec<u16>,
     initialized_days: Vec<(u16, u8)>,
+    #[serde(default)]
+    submissions: Vec<Submission>,
+}
+
+/// An answer submitted to the website, along with its verdict.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    #[serde(default)]
+    pub submitted_at: u64,
+}
+
+impl Submission {
+    pub fn new(year: u16, day: u8, part: Part, answer: &Answer, verdict: Verdict) -> Self {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+
+        Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        }
+    }
+}
+
+/// Why an answer is already known to be wrong, from earlier submissions.
+#[derive(Debug, PartialEq)]
+pub enum KnownWrong {
+    /// The same answer was submitted and rejected.
+    Repeated(Verdict),
+    /// The answer is at least as high as a guess that was too high.
+    AboveBound(i128),
+    /// The answer is at most as low as a guess that was too low.
+    BelowBound(i128),
+}
+
+impl fmt::Display for KnownWrong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnownWrong::Repeated(verdict) => write!(f, "was already submitted and is {}", verdict),
+            KnownWrong::AboveBound(bound) => write!(f, "is not below {}, which is too high", bound),
+            KnownWrong::BelowBound(bound) => write!(f, "is not above {}, which is too low", bound),
+        }
+    }
 }
 
 impl StateFile {
@@ -69,4 +131,130 @@ impl StateFile {
     pub fn has_initialized_day(&self, year: &u16, day: &u8) -> bool {
         self.initialized_days.contains(&(*year, *day))
     }
+
+    pub fn initialized_days(&self) -> &[(u16, u8)] {
+        &self.initialized_days
+    }
+
+    pub fn record_submission(&mut self, submission: Submission) -> Result<()> {
+        self.submissions.push(submission);
+        self.save()
+    }
+
+    /// The submissions of a part, oldest first.
+    pub fn submissions(&self, year: u16, day: u8, part: &Part) -> Vec<&Submission> {
+        self.submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == *part)
+            .collect()
+    }
+
+    /// Checks the answer against the earlier submissions of the part, before anyone submits it.
+    pub fn known_wrong(
+        &self,
+        year: u16,
+        day: u8,
+        part: &Part,
+        answer: &Answer,
+    ) -> Option<KnownWrong> {
+        let submissions = self.submissions(year, day, part);
+
+        if let Some(rejected) = submissions
+            .iter()
+            .find(|s| s.answer == answer.as_str() && s.verdict.is_wrong())
+        {
+            return Some(KnownWrong::Repeated(rejected.verdict.clone()));
+        }
+
+        let value = answer.as_str().parse::<i128>().ok()?;
+        let bound = |verdict: Verdict| {
+            submissions
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|high| value >= *high) {
+            return Some(KnownWrong::AboveBound(high));
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|low| value <= *low) {
+            return Some(KnownWrong::BelowBound(low));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn state_with(guesses: &[(i128, Verdict)]) -> StateFile {
+        StateFile {
+            submissions: guesses
+                .iter()
+                .map(|(answer, verdict)| {
+                    Submission::new(2024, 1, Part::One, &Answer::new(answer), verdict.clone())
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_known_wrong_repeated_guess() {
+        let state = state_with(&[(42, Verdict::Incorrect)]);
+
+        assert_eq!(
+            state.known_wrong(2024, 1, &Part::One, &Answer::new(42)),
+            Some(KnownWrong::Repeated(Verdict::Incorrect))
+        );
+        assert_eq!(
+            state.known_wrong(2024, 1, &Part::Two, &Answer::new(42)),
+            None
+        );
+        assert_eq!(
+            state.known_wrong(2024, 2, &Part::One, &Answer::new(42)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_known_wrong_bounds() {
+        let state = state_with(&[
+            (100, Verdict::TooHigh),
+            (10, Verdict::TooLow),
+            (80, Verdict::TooHigh),
+            (20, Verdict::TooLow),
+        ]);
+        let check = |answer| state.known_wrong(2024, 1, &Part::One, &Answer::new(answer));
+
+        assert_eq!(check(90), Some(KnownWrong::AboveBound(80)));
+        assert_eq!(check(80), Some(KnownWrong::Repeated(Verdict::TooHigh)));
+        assert_eq!(check(15), Some(KnownWrong::BelowBound(20)));
+        assert_eq!(check(50), None);
+
+        // Answers beyond `i64` are still compared, as they often are in later puzzles.
+        let state = state_with(&[(18_000_000_000_000_000_000, Verdict::TooLow)]);
+        assert_eq!(
+            state.known_wrong(
+                2024,
+                1,
+                &Part::One,
+                &Answer::new(17_000_000_000_000_000_000u64)
+            ),
+            Some(KnownWrong::BelowBound(18_000_000_000_000_000_000))
+        );
+    }
+
+    #[test]
+    fn test_known_wrong_ignores_non_numeric_answers() {
+        let state = state_with(&[(100, Verdict::TooHigh)]);
+
+        assert_eq!(
+            state.known_wrong(2024, 1, &Part::One, &Answer::new("ABC")),
+            None
+        );
+    }
 }
@@ -0,0 +1,225 @@
+//! A minimal HTML tokenizer, enough for the markup of the Advent of Code puzzle pages.
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Token {
+    Start {
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
+    End(String),
+    Text(String),
+}
+
+impl Token {
+    pub fn is_start(&self, tag: &str) -> bool {
+        matches!(self, Token::Start { name, .. } if name == tag)
+    }
+
+    pub fn is_end(&self, tag: &str) -> bool {
+        matches!(self, Token::End(name) if name == tag)
+    }
+
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Token::Start { attrs, .. } => attrs
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the document into tags and text, dropping comments, doctypes, scripts and styles.
+///
+/// Tag names are lowercased, entities in text and attribute values are decoded, and
+/// self-closing tags only produce a start token.
+pub fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(decode_entities(&rest[..start])));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::End(name.trim().to_lowercase()));
+            continue;
+        }
+
+        let (name, attrs) = parse_tag(tag.trim_end_matches('/'));
+        if name == "script" || name == "style" {
+            let close = format!("</{}", name);
+            rest = rest
+                .find(&close)
+                .or_else(|| rest.find(&close.to_uppercase()))
+                .and_then(|end| rest[end..].find('>').map(|close| &rest[end + close + 1..]))
+                .unwrap_or("");
+            continue;
+        }
+        tokens.push(Token::Start { name, attrs });
+    }
+
+    tokens
+}
+
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = tag[..name_end].to_lowercase();
+
+    let mut attrs = vec![];
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                let (value, remaining) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value = &value[1..];
+                        let end = value.find(quote).unwrap_or(value.len());
+                        (&value[..end], value.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                        (&value[..end], &value[end..])
+                    }
+                };
+                rest = remaining.trim_start();
+                decode_entities(value)
+            }
+            None => String::new(),
+        };
+
+        if !key.is_empty() {
+            attrs.push((key, value));
+        }
+    }
+
+    (name, attrs)
+}
+
+/// Decodes the named entities the puzzle pages use, along with numeric ones.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn start(name: &str, attrs: &[(&str, &str)]) -> Token {
+        Token::Start {
+            name: name.to_string(),
+            attrs: attrs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let html = r#"<!DOCTYPE html><!-- hi --><article class="day-desc"><p>A &lt;b&gt;</p><br/></article>"#;
+
+        assert_eq!(
+            tokenize(html),
+            vec![
+                start("article", &[("class", "day-desc")]),
+                start("p", &[]),
+                Token::Text("A <b>".to_string()),
+                Token::End("p".to_string()),
+                start("br", &[]),
+                Token::End("article".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_skips_scripts() {
+        let html = "<SCRIPT>if (a < b) {}</SCRIPT><P TITLE='x y'>z</P>";
+
+        assert_eq!(
+            tokenize(html),
+            vec![
+                start("p", &[("title", "x y")]),
+                Token::Text("z".to_string()),
+                Token::End("p".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("&quot;a&quot; &amp; &#39;b&#x27; &unknown; & c"),
+            "\"a\" & 'b' &unknown; & c"
+        );
+    }
+}
@@ -1,11 +1,27 @@
-use std::process::Command;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::{exit, Command},
+};
 
-use aocr::runner::Part;
-use clap::{Parser, Subcommand};
+use anyhow::Context;
+use aocr::{
+    answers::AnswersFile,
+    examples::Example,
+    runner::{self, Answer, Listing, OutputFormat, Part, Record},
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use client::{AocClient, Verdict};
 use colored::*;
-use config::StateFile;
+use config::{StateFile, Submission};
+use summary::{DaySummary, Summary};
 
+mod client;
 mod config;
+mod html;
+mod markdown;
+mod puzzle;
+mod summary;
 
 #[derive(Debug, Parser)]
 #[command(name = "aocr-cli", version, author, about, long_about)]
@@ -19,22 +35,75 @@ enum CommandCli {
     InitYear {
         #[arg(short, long)]
         year: u16,
+        /// Builds every day of the year into a single binary instead of one binary per day.
+        #[arg(long)]
+        single_binary: bool,
     },
     CreateDay {
         #[arg(short, long)]
         year: Option<u16>,
         #[arg(short, long)]
         day: u8,
+        /// Saves the puzzle description as a README.md in the day's directory.
+        #[arg(long)]
+        readme: bool,
+    },
+    Download {
+        #[arg(short, long)]
+        year: Option<u16>,
+        #[arg(short, long)]
+        day: Option<u8>,
+    },
+    /// Extracts the examples and their answers from the puzzle description.
+    Examples {
+        #[arg(short, long)]
+        year: Option<u16>,
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Reads the puzzle from a saved page instead of fetching it.
+        #[arg(long)]
+        html: Option<PathBuf>,
+        /// Overwrites the examples already saved, along with any answers edited in them.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Shows the puzzle description in the terminal.
+    Read {
+        #[arg(short, long)]
+        year: Option<u16>,
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Fetches the puzzle again instead of reading the cached copy.
+        #[arg(long)]
+        refresh: bool,
     },
     Run {
         #[arg(short, long)]
         year: Option<u16>,
         #[arg(short, long)]
         day: Option<u8>,
+        /// Runs every initialized day of the year, then sums up their answers in a table.
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Exports the summary of every day as a Markdown table.
+        #[arg(long, requires = "all", value_name = "PATH")]
+        export_markdown: Option<PathBuf>,
+        /// Exports the summary of every day as JSON.
+        #[arg(long, requires = "all", value_name = "PATH")]
+        export_json: Option<PathBuf>,
+        #[command(flatten)]
+        options: RunOptions,
+    },
+    /// Submits the last computed answer, or the given one, to the website.
+    Submit {
         #[arg(short, long)]
-        part: Option<Part>,
+        year: Option<u16>,
         #[arg(short, long)]
-        name: Option<String>,
+        day: Option<u8>,
+        /// Defaults to the first part without an accepted answer.
+        #[arg(short, long)]
+        part: Option<Part>,
+        answer: Option<String>,
     },
     Test {
         #[arg(short, long)]
@@ -44,6 +113,20 @@ enum CommandCli {
         #[arg(short, long)]
         name: Option<String>,
     },
+    Verify {
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Lists the solutions of every initialized day, with where they are defined.
+    List {
+        #[arg(short, long)]
+        year: Option<u16>,
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// How the solutions are listed, `plain` and `json` giving a line per solution.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+        output: OutputFormat,
+    },
     Set {
         #[arg(short, long)]
         year: u16,
@@ -53,7 +136,9 @@ enum CommandCli {
     Bench {
         solutions: Vec<String>,
         #[arg(short, long, default_value = "100")]
-        warmup: u8,
+        warmup: u32,
+        #[arg(short, long, default_value = "1000")]
+        runs: u32,
         #[arg(short, long)]
         year: Option<u16>,
         #[arg(short, long)]
@@ -63,30 +148,132 @@ enum CommandCli {
     },
 }
 
+// The options `run` passes on to the solutions' binary.
+#[derive(Args, Debug, Clone)]
+struct RunOptions {
+    #[arg(short, long)]
+    part: Option<Part>,
+    #[arg(short, long)]
+    name: Option<String>,
+    #[arg(long)]
+    no_timing: bool,
+    #[arg(long, conflicts_with = "name")]
+    all_variants: bool,
+    /// Runs against the day's example files instead of its input.
+    #[arg(long)]
+    example: bool,
+    /// Reads the puzzle input from this file instead, or from stdin with `-`.
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Reads the input of the named profile, stored as `{day}/{profile}.txt` next to the input.
+    #[arg(long, conflicts_with_all = ["all", "input"])]
+    profile: Option<String>,
+    /// Runs against the input of every profile, reporting the ones the solutions get wrong.
+    #[arg(long, conflicts_with_all = ["all", "input", "profile", "example", "all_variants"])]
+    all_profiles: bool,
+    /// How the results are reported, `plain` and `json` giving a line per solution run.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    output: OutputFormat,
+}
+
+impl RunOptions {
+    /// Whether the day's own input is run, which earlier submissions know about.
+    fn runs_own_input(&self) -> bool {
+        !self.example && self.input.is_none() && self.profile.is_none() && !self.all_profiles
+    }
+
+    fn apply(&self, runner: &mut Command) {
+        if let Some(name) = &self.name {
+            runner.arg("-n").arg(name);
+        }
+
+        if let Some(part) = &self.part {
+            runner.arg("-p").arg(part.as_str());
+        }
+
+        if self.no_timing {
+            runner.arg("--no-timing");
+        }
+
+        if self.all_variants {
+            runner.arg("--all-variants");
+        }
+
+        if self.example {
+            runner.arg("--example");
+        }
+
+        if let Some(input) = &self.input {
+            runner.arg("--input").arg(input);
+        }
+
+        if let Some(profile) = &self.profile {
+            runner.arg("--profile").arg(profile);
+        }
+
+        if self.all_profiles {
+            runner.arg("--all-profiles");
+        }
+
+        if let Some(output) = self.output.to_possible_value() {
+            runner.arg("--output").arg(output.get_name());
+        }
+    }
+}
+
 fn main() {
     let cli = AocCli::parse();
     match cli.command {
-        CommandCli::InitYear { year } => init_year(year),
-        CommandCli::CreateDay { year, day } => create_day(year, day),
+        CommandCli::InitYear {
+            year,
+            single_binary,
+        } => init_year(year, single_binary),
+        CommandCli::CreateDay { year, day, readme } => create_day(year, day, readme),
+        CommandCli::Download { year, day } => download(year, day),
+        CommandCli::Examples {
+            year,
+            day,
+            html,
+            force,
+        } => extract_examples(year, day, html, force),
+        CommandCli::Read { year, day, refresh } => read(year, day, refresh),
+        CommandCli::Run {
+            year,
+            day,
+            all: false,
+            options,
+            ..
+        } => run_solution(year, day, options),
         CommandCli::Run {
+            year,
+            all: true,
+            export_markdown,
+            export_json,
+            options,
+            ..
+        } => run_all(year, options, export_markdown, export_json),
+        CommandCli::Submit {
             year,
             day,
             part,
-            name,
-        } => run_solution(year, day, part, name),
+            answer,
+        } => submit(year, day, part, answer),
         CommandCli::Test { year, day, name } => test_solution(year, day, name),
+        CommandCli::Verify { year } => verify(year),
+        CommandCli::List { year, day, output } => list(year, day, output),
         CommandCli::Set { year, day } => set(year, day),
         CommandCli::Bench {
             solutions,
             warmup,
+            runs,
             day,
             year,
             part,
-        } => bench(solutions, warmup, day, year, part),
+        } => bench(solutions, warmup, runs, day, year, part),
     }
 }
 
-fn init_year(year: u16) {
+fn init_year(year: u16, single_binary: bool) {
     let mut state = StateFile::load().unwrap();
     if state.has_initialized_year(&year) {
         panic!("Year {} is already initialized", year);
@@ -103,13 +290,21 @@ fn init_year(year: u16) {
         .status()
         .unwrap();
 
-    create_day(Some(year), 1);
+    if single_binary {
+        fs::write(
+            format!("aoc_{}/src/main.rs", year),
+            format!("aocr::main!({});\n", year),
+        )
+        .unwrap();
+    }
+
+    create_day(Some(year), 1, false);
 
     Command::new("cargo")
         .arg("add")
         .arg("-p")
         .arg(format!("aoc_{}", year))
-        .args(["anyhow", "aocr", "aocr_macro", "ctor"])
+        .args(["anyhow", "aocr", "aocr_macro", "miette"])
         .status()
         .unwrap();
 
@@ -123,80 +318,509 @@ fn init_year(year: u16) {
         .unwrap();
 }
 
-fn create_day(year: Option<u16>, day: u8) {
+fn create_day(year: Option<u16>, day: u8, readme: bool) {
     let mut state = StateFile::load().unwrap();
     let year = year.or(state.current_year).unwrap();
     if state.has_initialized_day(&year, &day) {
         panic!("Day {} is already initialized for year {}", day, year);
     }
     if !state.has_initialized_year(&year) {
-        init_year(year);
+        init_year(year, false);
     }
     state.set_current_day(day, year).unwrap();
 
+    let single_binary = is_single_binary(year);
+    let (name, template, destination) = match single_binary {
+        true => (format!("day{:02}", day), "module", "src"),
+        false => (format!("{:02}", day), "day", "src/bin"),
+    };
     Command::new("cargo")
         .arg("generate")
         .arg("--name")
-        .arg(format!("{:02}", day))
+        .arg(name)
         .arg("--path")
-        .arg("./aocr_template/day")
+        .arg(format!("./aocr_template/{}", template))
         .arg("--destination")
-        .arg(format!("./aoc_{}/src/bin", year))
+        .arg(format!("./aoc_{}/{}", year, destination))
         .arg("--define")
         .arg(format!("year={}", year))
         .arg("--define")
         .arg(format!("day={:02}", day))
+        .arg("--define")
+        .arg(format!("day_number={}", day))
         .status()
         .unwrap();
 
-    Command::new("mkdir")
-        .arg("-p")
-        .arg(format!("inputs/{}", year))
-        .status()
-        .unwrap();
+    if single_binary {
+        declare_day_module(year, day).unwrap();
+    }
 
-    Command::new("aoc")
-        .arg("d")
-        .arg("-I")
-        .arg("-i")
-        .arg(format!("inputs/{}/{:02}.txt", year, day))
-        .arg("-d")
-        .arg(format!("{}", day))
-        .arg("-y")
-        .arg(format!("{}", year))
-        .status()
-        .unwrap();
+    download(Some(year), Some(day));
+
+    if readme {
+        let readme_path = day_dir(year, day).join("README.md");
+        match puzzle_page(year, day, false) {
+            Ok(page) => {
+                fs::write(
+                    &readme_path,
+                    markdown::render(&page, false, &client::base_url()),
+                )
+                .unwrap();
+                println!("📖 Puzzle saved to {}", readme_path.display());
+            }
+            Err(e) => eprintln!("{} {:#}", "Failed to get the puzzle:".red().bold(), e),
+        }
+    }
+}
+
+/// Whether the year builds every day into a single binary, with a `dayNN` module per day.
+fn is_single_binary(year: u16) -> bool {
+    Path::new(&format!("aoc_{}/src/main.rs", year)).exists()
 }
 
-fn run_solution(year: Option<u16>, day: Option<u8>, part: Option<Part>, name: Option<String>) {
+/// Declares the day's module in the `main.rs` of a single binary year, keeping them in order.
+fn declare_day_module(year: u16, day: u8) -> anyhow::Result<()> {
+    let main_path = PathBuf::from(format!("aoc_{}/src/main.rs", year));
+    let main = fs::read_to_string(&main_path)
+        .with_context(|| format!("Failed to read {}", main_path.display()))?;
+
+    let declaration = format!("mod day{:02};", day);
+    let mut lines = main.lines().collect::<Vec<_>>();
+    let modules = lines
+        .iter()
+        .take_while(|line| line.starts_with("mod "))
+        .count();
+    let position = lines[..modules]
+        .iter()
+        .position(|line| *line > declaration.as_str())
+        .unwrap_or(modules);
+    lines.insert(position, &declaration);
+    if modules == 0 {
+        lines.insert(1, "");
+    }
+
+    fs::write(&main_path, format!("{}\n", lines.join("\n")))
+        .with_context(|| format!("Failed to write {}", main_path.display()))
+}
+
+/// The input of the day, under `AOCR_INPUTS_DIR` when set like the runner does.
+fn input_path(year: u16, day: u8) -> PathBuf {
+    runner::input_path(&runner::inputs_dir(Path::new("")), year, day)
+}
+
+fn day_dir(year: u16, day: u8) -> PathBuf {
+    match is_single_binary(year) {
+        true => PathBuf::from(format!("aoc_{}/src/day{:02}", year, day)),
+        false => PathBuf::from(format!("aoc_{}/src/bin/{:02}", year, day)),
+    }
+}
+
+/// The `cargo run` of the day's solutions, up to the arguments of the runner.
+fn day_runner(year: u16, day: u8, release: bool) -> Command {
+    let mut runner = Command::new("cargo");
+    runner.arg("run").arg("-q");
+    if release {
+        runner.arg("--release");
+    }
+    runner.arg("-p").arg(format!("aoc_{}", year));
+
+    if is_single_binary(year) {
+        runner.arg("--").arg("--day").arg(day.to_string());
+    } else {
+        runner.arg("--bin").arg(format!("{:02}", day)).arg("--");
+    }
+
+    runner
+}
+
+/// The binary [`day_runner`] runs the day with, as people know it.
+fn runner_name(year: u16, day: u8) -> String {
+    match is_single_binary(year) {
+        true => format!("aoc_{} --day {}", year, day),
+        false => format!("aoc_{} binary {:02}", year, day),
+    }
+}
+
+/// The puzzle page of the day, fetched once and then read from the day's directory.
+///
+/// Part two only appears in the page once part one is solved, so `refresh` fetches it again.
+fn puzzle_page(year: u16, day: u8, refresh: bool) -> anyhow::Result<String> {
+    let cache_path = day_dir(year, day).join("puzzle.html");
+    if !refresh {
+        if let Ok(page) = fs::read_to_string(&cache_path) {
+            return Ok(page);
+        }
+    }
+
+    let page = AocClient::from_env()?.puzzle(year, day)?;
+    if cache_path.parent().is_some_and(|dir| dir.is_dir()) {
+        fs::write(&cache_path, &page)
+            .with_context(|| format!("Failed to write {}", cache_path.display()))?;
+    }
+
+    Ok(page)
+}
+
+fn read(year: Option<u16>, day: Option<u8>, refresh: bool) {
     let state = StateFile::load().unwrap();
     let day = day.or(state.current_day).unwrap();
     let year = year.or(state.current_year).unwrap();
 
-    let message = format!("🎄 Running AoC {}/{:02}\n", year, day)
-        .bold()
-        .purple();
-    println!("{}", message);
+    match puzzle_page(year, day, refresh) {
+        Ok(page) => print!("{}", markdown::render(&page, true, &client::base_url())),
+        Err(e) => {
+            eprintln!("{} {:#}", "Failed to get the puzzle:".red().bold(), e);
+            exit(1);
+        }
+    }
+}
 
-    let mut runner = Command::new("cargo");
-    runner
-        .arg("run")
-        .arg("-q")
-        .arg("-p")
-        .arg(format!("aoc_{}", year))
-        .arg("--bin")
-        .arg(format!("{:02}", day))
-        .arg("--");
+fn download(year: Option<u16>, day: Option<u8>) {
+    let state = StateFile::load().unwrap();
+    let day = day.or(state.current_day).unwrap();
+    let year = year.or(state.current_year).unwrap();
+
+    let input_path = input_path(year, day);
+    let downloaded =
+        AocClient::from_env().and_then(|client| client.download_input(year, day, &input_path));
 
-    if let Some(name) = name {
-        runner.arg("-n").arg(name);
+    match downloaded {
+        Ok(true) => println!("📥 Input saved to {}", input_path.display()),
+        Ok(false) => println!(
+            "{}",
+            format!("Input already downloaded to {}", input_path.display()).dimmed()
+        ),
+        Err(e) => eprintln!("{} {:#}", "Failed to download input:".red().bold(), e),
+    }
+}
+
+fn extract_examples(year: Option<u16>, day: Option<u8>, html: Option<PathBuf>, force: bool) {
+    let state = StateFile::load().unwrap();
+    let day = day.or(state.current_day).unwrap();
+    let year = year.or(state.current_year).unwrap();
+
+    let page = match html {
+        Some(path) => {
+            fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+        }
+        None => puzzle_page(year, day, false),
+    };
+    let page = page.unwrap_or_else(|e| {
+        eprintln!("{} {:#}", "Failed to get the puzzle:".red().bold(), e);
+        exit(1);
+    });
+
+    let descriptions = puzzle::parse_descriptions(&page);
+    if descriptions.is_empty() {
+        eprintln!("{}", "No puzzle description found in the page".red().bold());
+        exit(1);
+    }
+
+    let input_path = input_path(year, day);
+    let mut examples: Vec<Example> = vec![];
+    for (part, description) in [Part::One, Part::Two].into_iter().zip(descriptions) {
+        let Some(answer) = description.answer else {
+            println!("⏭️  Part {} {}", part, "skipped, no answer found".dimmed());
+            continue;
+        };
+
+        let choice = puzzle::choose_block(
+            part.as_str(),
+            &description.code_blocks,
+            &mut io::stdin().lock(),
+            &mut io::stdout(),
+        )
+        .unwrap();
+        // Part two usually reuses the example of part one instead of showing a new one.
+        let example_input = match choice {
+            Some(i) => description.code_blocks[i].clone(),
+            None if description.code_blocks.is_empty() && !examples.is_empty() => {
+                examples[examples.len() - 1].input().to_string()
+            }
+            None => {
+                println!(
+                    "⏭️  Part {} {}",
+                    part,
+                    "skipped, no example chosen".dimmed()
+                );
+                continue;
+            }
+        };
+
+        let example = match examples.iter_mut().find(|e| e.input() == example_input) {
+            Some(example) => example,
+            None => {
+                let path = input_path.with_extension(format!("example{}.txt", examples.len() + 1));
+                examples.push(Example::new(path, example_input));
+                examples.last_mut().unwrap()
+            }
+        };
+        example.set_expected(part, Answer::new(answer));
     }
 
-    if let Some(part) = part {
-        runner.arg("-p").arg(part.as_str());
+    if let Some(dir) = input_path.parent() {
+        fs::create_dir_all(dir).unwrap();
     }
+    for example in examples {
+        // Examples may have been fixed up by hand, which is only thrown away when asked to.
+        if example.path().exists() && !force {
+            println!(
+                "📎 Example kept at {} {}",
+                example.path().display(),
+                "(already exists, overwrite it with --force)".dimmed()
+            );
+            continue;
+        }
 
+        example.save().unwrap();
+        println!("📝 Example saved to {}", example.path().display());
+    }
+}
+
+fn run_solution(year: Option<u16>, day: Option<u8>, options: RunOptions) {
+    let state = StateFile::load().unwrap();
+    let day = day.or(state.current_day).unwrap();
+    let year = year.or(state.current_year).unwrap();
+
+    let pretty = options.output == OutputFormat::Pretty;
+    if pretty {
+        let message = format!("🎄 Running AoC {}/{:02}\n", year, day)
+            .bold()
+            .purple();
+        println!("{}", message);
+    }
+
+    let mut runner = day_runner(year, day, false);
+    options.apply(&mut runner);
     runner.status().unwrap();
+
+    if pretty && options.runs_own_input() {
+        warn_known_wrong(&state, year, day, options.part);
+    }
+}
+
+/// Runs every initialized day of the year, in a single process when the year is a single binary,
+/// and prints a summary of their answers.
+fn run_all(
+    year: Option<u16>,
+    options: RunOptions,
+    export_markdown: Option<PathBuf>,
+    export_json: Option<PathBuf>,
+) {
+    let state = StateFile::load().unwrap();
+    let year = year.or(state.current_year).unwrap();
+
+    let pretty = options.output == OutputFormat::Pretty;
+    if pretty {
+        let message = format!("🎄 Running AoC {}\n", year).bold().purple();
+        println!("{}", message);
+    }
+
+    let days = state
+        .initialized_days()
+        .iter()
+        .filter(|(y, _)| *y == year)
+        .map(|&(_, day)| day)
+        .collect::<Vec<_>>();
+
+    // Runners of a single day, whose records belong to that day, or of the whole year.
+    let mut runners = vec![];
+    if is_single_binary(year) {
+        let mut runner = Command::new("cargo");
+        runner
+            .arg("run")
+            .arg("-q")
+            .arg("-p")
+            .arg(format!("aoc_{}", year))
+            .arg("--")
+            .arg("--all");
+        runners.push((None, runner));
+    } else {
+        runners.extend(
+            days.iter()
+                .map(|&day| (Some(day), day_runner(year, day, false))),
+        );
+    }
+
+    // Examples aren't the day's answers, so there's nothing to sum up.
+    if options.example {
+        for (_, mut runner) in runners {
+            options.apply(&mut runner);
+            runner.status().unwrap();
+        }
+        return;
+    }
+
+    // The summary is built from the records the runners print in JSON, a line per solution run,
+    // which only carry the times when they're not left out.
+    let json_options = RunOptions {
+        output: OutputFormat::Json,
+        no_timing: false,
+        ..options.clone()
+    };
+    let mut records = vec![];
+    for (day, mut runner) in runners {
+        json_options.apply(&mut runner);
+        let output = runner.output().unwrap();
+        if !output.status.success() {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+
+        records.extend(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| serde_json::from_str::<Record>(line).ok())
+                .filter_map(|record| Some((day.or(record.day)?, record))),
+        );
+    }
+
+    let parts = options
+        .part
+        .clone()
+        .map_or_else(|| Part::value_variants().to_vec(), |part| vec![part]);
+    let days_summaries = days
+        .iter()
+        .map(|&day| {
+            let records = records
+                .iter()
+                .filter(|(record_day, _)| *record_day == day)
+                .map(|(_, record)| record.clone())
+                .collect::<Vec<_>>();
+            DaySummary::from_records(day, &records, &parts)
+        })
+        .collect();
+    let summary = Summary::new(year, days_summaries);
+
+    let table = summary.markdown();
+    match options.output {
+        OutputFormat::Json => println!("{}", serde_json::to_string(&summary).unwrap()),
+        _ => println!("{}", table),
+    }
+    if let Some(path) = export_markdown {
+        fs::write(&path, &table).unwrap();
+        if pretty {
+            println!("📝 Summary saved to {}", path.display());
+        }
+    }
+    if let Some(path) = export_json {
+        fs::write(&path, serde_json::to_string_pretty(&summary).unwrap()).unwrap();
+        if pretty {
+            println!("📝 Summary saved to {}", path.display());
+        }
+    }
+
+    if !pretty {
+        return;
+    }
+    for day in days {
+        warn_known_wrong(&state, year, day, options.part.clone());
+    }
+}
+
+/// Warns about computed answers that earlier submissions already ruled out.
+fn warn_known_wrong(state: &StateFile, year: u16, day: u8, part: Option<Part>) {
+    let input_path = input_path(year, day);
+    let answers = AnswersFile::load(&input_path).unwrap();
+    let parts = part.map_or_else(|| Part::value_variants().to_vec(), |part| vec![part]);
+
+    for part in parts.iter().filter(|part| answers.get(part).is_none()) {
+        let Some(answer) = answers.last(part) else {
+            continue;
+        };
+        if let Some(reason) = state.known_wrong(year, day, part, &answer) {
+            println!(
+                "⚠️  {} {} (part {})",
+                answer.as_str().yellow().bold(),
+                reason,
+                part
+            );
+        }
+    }
+}
+
+fn submit(year: Option<u16>, day: Option<u8>, part: Option<Part>, answer: Option<String>) {
+    let mut state = StateFile::load().unwrap();
+    let day = day.or(state.current_day).unwrap();
+    let year = year.or(state.current_year).unwrap();
+
+    let input_path = input_path(year, day);
+    let mut answers = AnswersFile::load(&input_path).unwrap();
+
+    let Some(part) = part.or_else(|| {
+        Part::value_variants()
+            .iter()
+            .find(|part| answers.get(part).is_none())
+            .cloned()
+    }) else {
+        println!("⭐ Both parts of {}/{:02} are already solved", year, day);
+        return;
+    };
+
+    if let Some(accepted) = answers.get(&part) {
+        println!(
+            "⭐ Part {} of {}/{:02} is already solved with {}",
+            part,
+            year,
+            day,
+            accepted.as_str().green()
+        );
+        return;
+    }
+
+    let Some(answer) = answer.map(Answer::new).or_else(|| answers.last(&part)) else {
+        eprintln!(
+            "{} part {} of {}/{:02}, run it first",
+            "No answer computed for".red().bold(),
+            part,
+            year,
+            day
+        );
+        exit(1);
+    };
+
+    if let Some(reason) = state.known_wrong(year, day, &part, &answer) {
+        eprintln!("❌ {} {}", answer.as_str().red().bold(), reason);
+        exit(1);
+    }
+
+    println!(
+        "📤 Submitting {} for part {} of {}/{:02}",
+        answer.as_str().bold(),
+        part,
+        year,
+        day
+    );
+    let verdict = AocClient::from_env()
+        .and_then(|client| client.submit_answer(year, day, &part, &answer))
+        .unwrap_or_else(|e| {
+            eprintln!("{} {:#}", "Failed to submit answer:".red().bold(), e);
+            exit(1);
+        });
+
+    match &verdict {
+        Verdict::Correct => {
+            println!("✅ {}", "That's the right answer!".green().bold());
+            answers.record(&part, &answer);
+            answers.save().unwrap();
+            // The cached puzzle doesn't have the next part yet.
+            let _ = fs::remove_file(day_dir(year, day).join("puzzle.html"));
+        }
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect => {
+            println!("❌ {} is {}", answer.as_str().red().bold(), verdict)
+        }
+        Verdict::RateLimited { .. } => println!("⏳ Submitted too recently, {}", verdict),
+        Verdict::WrongLevel => {
+            println!("⚠️  Part {} isn't open, is the previous part solved?", part)
+        }
+    }
+
+    if matches!(verdict, Verdict::RateLimited { .. } | Verdict::WrongLevel) {
+        return;
+    }
+
+    state
+        .record_submission(Submission::new(year, day, part, &answer, verdict))
+        .unwrap();
 }
 
 fn test_solution(year: Option<u16>, day: Option<u8>, name: Option<String>) {
@@ -205,54 +829,206 @@ fn test_solution(year: Option<u16>, day: Option<u8>, name: Option<String>) {
     let year = year.or(state.current_year).unwrap();
 
     let mut runner = Command::new("cargo");
-    runner
-        .arg("test")
-        .arg("-p")
-        .arg(format!("aoc_{}", year))
-        .arg("--bin")
-        .arg(format!("{:02}", day));
+    runner.arg("test").arg("-p").arg(format!("aoc_{}", year));
 
-    if let Some(name) = name {
-        runner.args([name]);
+    if is_single_binary(year) {
+        // Every day's tests live in the same binary, under the day's module.
+        runner.arg(name.unwrap_or_else(|| format!("day{:02}::", day)));
+    } else {
+        runner.arg("--bin").arg(format!("{:02}", day));
+        if let Some(name) = name {
+            runner.args([name]);
+        }
     }
 
     runner.status().unwrap();
 }
 
+fn verify(year: Option<u16>) {
+    let state = StateFile::load().unwrap();
+    let days = state
+        .initialized_days()
+        .iter()
+        .filter(|(y, _)| year.is_none_or(|year| *y == year));
+
+    let mut regressions = vec![];
+    for &(year, day) in days {
+        let label = format!("{}/{:02}", year, day);
+        let input_path = input_path(year, day);
+        if !input_path.exists() {
+            println!("⏭️  {} {}", label.bold(), "skipped, no input".dimmed());
+            continue;
+        }
+        if AnswersFile::load(&input_path).unwrap().is_empty() {
+            println!(
+                "⏭️  {} {}",
+                label.bold(),
+                "skipped, no accepted answers".dimmed()
+            );
+            continue;
+        }
+
+        let output = day_runner(year, day, false)
+            .arg("--all-variants")
+            .arg("--no-timing")
+            .output()
+            .unwrap();
+
+        if output.status.success() {
+            println!("✅ {}", label.bold());
+        } else {
+            println!("❌ {}", label.bold());
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            regressions.push(label);
+        }
+    }
+
+    if !regressions.is_empty() {
+        eprintln!(
+            "\n{} {}",
+            "Regressions found in".red().bold(),
+            regressions.join(", ").red().bold()
+        );
+        exit(1);
+    }
+}
+
+fn list(year: Option<u16>, day: Option<u8>, output: OutputFormat) {
+    let state = StateFile::load().unwrap();
+    let days = state
+        .initialized_days()
+        .iter()
+        .filter(|(y, d)| year.is_none_or(|year| *y == year) && day.is_none_or(|day| *d == day))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let mut listings = vec![];
+    let mut failures = vec![];
+    for &(year, day) in &days {
+        let result = day_runner(year, day, false)
+            .arg("--list")
+            .arg("--output")
+            .arg("json")
+            .output()
+            .unwrap();
+        if !result.status.success() {
+            eprint!("{}", String::from_utf8_lossy(&result.stderr));
+            failures.push(format!("{}/{:02}", year, day));
+            continue;
+        }
+
+        let parsed = String::from_utf8_lossy(&result.stdout)
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<Listing>(line).with_context(|| {
+                    format!(
+                        "{} printed a line that isn't a listing: {}",
+                        runner_name(year, day),
+                        line
+                    )
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>();
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("{} {:#}", "Failed to read the listing:".red().bold(), e);
+                failures.push(format!("{}/{:02}", year, day));
+                continue;
+            }
+        };
+
+        // Solutions of packages not named after their year don't know their puzzle.
+        listings.extend(parsed.into_iter().map(|listing| Listing {
+            year: listing.year.or(Some(year)),
+            day: listing.day.or(Some(day)),
+            ..listing
+        }));
+    }
+
+    match output {
+        OutputFormat::Json => {
+            for listing in &listings {
+                println!("{}", serde_json::to_string(listing).unwrap());
+            }
+        }
+        OutputFormat::Plain => {
+            for listing in &listings {
+                let line = format!(
+                    "{}/{:02} part {} {} {} {}",
+                    listing.year.unwrap_or_default(),
+                    listing.day.unwrap_or_default(),
+                    listing.part,
+                    listing.name,
+                    listing.location(),
+                    listing.summary()
+                );
+                println!("{}", line.trim_end());
+            }
+        }
+        OutputFormat::Pretty => {
+            let mut years = days.iter().map(|(year, _)| *year).collect::<Vec<_>>();
+            years.dedup();
+            for year in years {
+                let year_listings = listings
+                    .iter()
+                    .filter(|listing| listing.year == Some(year))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if year_listings.is_empty() {
+                    continue;
+                }
+                println!("{}\n", format!("🎄 AoC {}", year).bold().purple());
+                println!("{}", runner::listing_table(&year_listings));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        eprintln!(
+            "{} {}",
+            "Failed to list the solutions of".red().bold(),
+            failures.join(", ").red().bold()
+        );
+        exit(1);
+    }
+}
+
 fn set(year: u16, day: u8) {
     let mut state = StateFile::load().unwrap();
     state.set_current_day(day, year).unwrap();
 }
 
-fn bench(solutions: Vec<String>, warmup: u8, day: Option<u8>, year: Option<u16>, part: Part) {
+fn bench(
+    solutions: Vec<String>,
+    warmup: u32,
+    runs: u32,
+    day: Option<u8>,
+    year: Option<u16>,
+    part: Part,
+) {
     let state = StateFile::load().unwrap();
     let day = day.or(state.current_day).unwrap();
     let year = year.or(state.current_year).unwrap();
 
-    Command::new("cargo")
-        .arg("build")
-        .arg("--release")
+    let mut bench = day_runner(year, day, true);
+    bench
+        .arg("--bench")
+        .arg("--warmup")
+        .arg(warmup.to_string())
+        .arg("--runs")
+        .arg(runs.to_string())
         .arg("-p")
-        .arg(format!("aoc_{}", year))
-        .arg("--bin")
-        .arg(format!("{:02}", day))
-        .status()
-        .expect("Failed to build binary");
-
-    let binary_path = format!("./target/release/{:02}", day);
-
-    let mut bench = Command::new("hyperfine");
-    bench.arg("--warmup").arg(warmup.to_string());
+        .arg(part.as_str());
 
     for solution in solutions {
-        let cmd = format!("{} -n {} -p {}", binary_path, solution, part.as_str());
-        bench.arg("-n").arg(solution).arg(cmd);
+        bench.arg("-n").arg(solution);
     }
 
-    bench.arg("--export-markdown").arg(format!(
-        "aoc_{year}/src/bin/{day:02}/benchmark-part_{}.md",
-        part.as_str()
-    ));
+    bench
+        .arg("--export-markdown")
+        .arg(day_dir(year, day).join(format!("benchmark-part_{}.md", part.as_str())));
 
-    bench.arg("-N").status().unwrap();
+    bench.status().unwrap();
 }
@@ -0,0 +1,254 @@
+//! Renders the puzzle description as Markdown, either plain or styled for the terminal.
+
+use colored::*;
+
+use crate::html::{tokenize, Token};
+
+/// An inline element being rendered, such as `<em>` or `<a>`.
+struct Span {
+    tag: String,
+    href: Option<String>,
+    text: String,
+    emphasized: bool,
+}
+
+struct Renderer<'a> {
+    styled: bool,
+    /// The website relative links point to.
+    base_url: &'a str,
+    output: String,
+    line: String,
+    spans: Vec<Span>,
+    code_block: Option<String>,
+}
+
+/// Renders the parts of the puzzle found in the page, ignoring everything around them.
+///
+/// Plain rendering gives Markdown fit for a `README.md`, while styled rendering swaps the
+/// Markdown markers for terminal colors. Links relative to the website are made absolute with
+/// `base_url`.
+pub fn render(html: &str, styled: bool, base_url: &str) -> String {
+    let mut renderer = Renderer {
+        styled,
+        base_url,
+        output: String::new(),
+        line: String::new(),
+        spans: vec![],
+        code_block: None,
+    };
+
+    let mut in_article = false;
+    for token in tokenize(html) {
+        if token.is_start("article") && token.attr("class") == Some("day-desc") {
+            in_article = true;
+        } else if token.is_end("article") {
+            in_article = false;
+        } else if in_article {
+            renderer.token(token);
+        }
+    }
+
+    match renderer.output.trim_end() {
+        "" => String::new(),
+        output => format!("{}\n", output),
+    }
+}
+
+impl Renderer<'_> {
+    fn token(&mut self, token: Token) {
+        // Code blocks are kept verbatim, markup included.
+        if self.code_block.is_some() && !token.is_end("pre") {
+            if let Token::Text(text) = token {
+                self.text(&text);
+            }
+            return;
+        }
+
+        match token {
+            Token::Text(text) => self.text(&text),
+            Token::Start { name, attrs } => match name.as_str() {
+                "pre" => self.code_block = Some(String::new()),
+                "br" => self.line.push('\n'),
+                "em" | "code" | "a" | "span" => self.spans.push(Span {
+                    tag: name,
+                    href: attrs
+                        .into_iter()
+                        .find(|(key, _)| key == "href")
+                        .map(|(_, href)| absolute_url(self.base_url, &href)),
+                    text: String::new(),
+                    emphasized: false,
+                }),
+                _ => {}
+            },
+            Token::End(name) => match name.as_str() {
+                "h2" => self.flush_block("\n\n", |line, styled| match styled {
+                    true => line.bold().purple().to_string(),
+                    false => format!("## {}", line),
+                }),
+                "p" => self.flush_block("\n\n", |line, _| line.to_string()),
+                "li" => self.flush_block("\n", |line, _| format!("- {}", line)),
+                "ul" => self.output.push('\n'),
+                "pre" => self.flush_code_block(),
+                "em" | "code" | "a" | "span" => self.close_span(&name),
+                _ => {}
+            },
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some(code_block) = self.code_block.as_mut() {
+            code_block.push_str(text);
+            return;
+        }
+
+        let target = match self.spans.last_mut() {
+            Some(span) => &mut span.text,
+            None => &mut self.line,
+        };
+        for (i, word) in text.split_whitespace().enumerate() {
+            let separated = i > 0 || text.starts_with(char::is_whitespace);
+            if separated && !target.is_empty() && !target.ends_with(['\n', ' ']) {
+                target.push(' ');
+            }
+            target.push_str(word);
+        }
+        if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+            target.push(' ');
+        }
+    }
+
+    fn close_span(&mut self, tag: &str) {
+        let Some(span) = self.spans.pop().filter(|span| span.tag == tag) else {
+            return;
+        };
+
+        let parent_is_code = self.spans.last().is_some_and(|parent| parent.tag == "code");
+        let rendered = match (span.tag.as_str(), self.styled) {
+            ("em", _) if parent_is_code => {
+                if let Some(parent) = self.spans.last_mut() {
+                    parent.emphasized = true;
+                }
+                span.text
+            }
+            ("em", true) => span.text.bold().bright_white().to_string(),
+            ("em", false) => format!("**{}**", span.text),
+            ("code", true) if span.emphasized => span.text.yellow().bold().to_string(),
+            ("code", true) => span.text.yellow().to_string(),
+            ("code", false) if span.emphasized => format!("**`{}`**", span.text),
+            ("code", false) => format!("`{}`", span.text),
+            ("a", true) => span.text.blue().underline().to_string(),
+            ("a", false) => match span.href {
+                Some(href) => format!("[{}]({})", span.text, href),
+                None => span.text,
+            },
+            _ => span.text,
+        };
+
+        match self.spans.last_mut() {
+            Some(parent) => parent.text.push_str(&rendered),
+            None => self.line.push_str(&rendered),
+        }
+    }
+
+    /// Ends the current block, rendering its text with `format` and following it with `end`.
+    fn flush_block(&mut self, end: &str, format: impl Fn(&str, bool) -> String) {
+        while let Some(span) = self.spans.last() {
+            let tag = span.tag.clone();
+            self.close_span(&tag);
+        }
+
+        let line = std::mem::take(&mut self.line);
+        let line = line.trim();
+        if !line.is_empty() {
+            self.output.push_str(&format(line, self.styled));
+            self.output.push_str(end);
+        }
+    }
+
+    fn flush_code_block(&mut self) {
+        let Some(code_block) = self.code_block.take() else {
+            return;
+        };
+        let code_block = code_block.trim_end_matches('\n');
+
+        if self.styled {
+            for line in code_block.lines() {
+                self.output.push_str(&format!("    {}\n", line.dimmed()));
+            }
+            self.output.push('\n');
+        } else {
+            self.output
+                .push_str(&format!("```\n{}\n```\n\n", code_block));
+        }
+    }
+}
+
+fn absolute_url(base_url: &str, href: &str) -> String {
+    if href.starts_with('/') {
+        format!("{}{}", base_url, href)
+    } else {
+        href.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const BASE_URL: &str = "https://adventofcode.com";
+
+    const PUZZLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>The <em>Chief Historian</em> is always present for the big Christmas sleigh launch.</p>
+<ul>
+<li>The first <span title="Easter egg">pair</span>.</li>
+<li>Get your <a href="/2024/day/1/input" target="_blank">puzzle input</a>.</li>
+</ul>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>A total distance of <code><em>11</em></code>, not <code>12</code>!</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+</main>"#;
+
+    #[test]
+    fn test_render_plain() {
+        let expected = "\
+## --- Day 1: Historian Hysteria ---
+
+The **Chief Historian** is always present for the big Christmas sleigh launch.
+
+- The first pair.
+- Get your [puzzle input](https://adventofcode.com/2024/day/1/input).
+
+```
+3   4
+4   3
+```
+
+A total distance of **`11`**, not `12`!
+";
+
+        assert_eq!(render(PUZZLE, false, BASE_URL), expected);
+        assert!(render(PUZZLE, false, "http://localhost:8080")
+            .contains("[puzzle input](http://localhost:8080/2024/day/1/input)"));
+    }
+
+    #[test]
+    fn test_render_styled() {
+        colored::control::set_override(false);
+
+        let rendered = render(PUZZLE, true, BASE_URL);
+
+        assert!(rendered.starts_with("--- Day 1: Historian Hysteria ---\n\nThe Chief Historian"));
+        assert!(rendered.contains("\n    3   4\n    4   3\n"));
+        assert!(rendered.ends_with("A total distance of 11, not 12!\n"));
+    }
+
+    #[test]
+    fn test_render_without_puzzle() {
+        assert_eq!(render("<p>Not found</p>", false, BASE_URL), "");
+    }
+}
@@ -0,0 +1,184 @@
+//! Extraction of the examples from a puzzle description.
+
+use std::io::{BufRead, Write};
+
+use anyhow::{bail, Result};
+
+use crate::html::{tokenize, Token};
+
+/// What the description of one part offers for building examples.
+#[derive(Debug, PartialEq, Default)]
+pub struct PartDescription {
+    /// The text of every `<pre><code>` block, in order.
+    pub code_blocks: Vec<String>,
+    /// The last emphasized code of the description, which is where the example's answer goes.
+    pub answer: Option<String>,
+}
+
+/// Splits the puzzle page into the descriptions of its parts.
+///
+/// Part two only shows up in the page once part one is solved.
+pub fn parse_descriptions(html: &str) -> Vec<PartDescription> {
+    let tokens = tokenize(html);
+    let mut descriptions = vec![];
+    let mut current: Option<PartDescription> = None;
+    let mut code_block: Option<String> = None;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is_start("article") && token.attr("class") == Some("day-desc") {
+            current = Some(PartDescription::default());
+            continue;
+        }
+        let Some(description) = current.as_mut() else {
+            continue;
+        };
+
+        match token {
+            Token::End(name) if name == "article" => {
+                descriptions.extend(current.take());
+            }
+            Token::Start { name, .. } if name == "pre" => code_block = Some(String::new()),
+            Token::End(name) if name == "pre" => {
+                description.code_blocks.extend(code_block.take());
+            }
+            Token::Text(text) => {
+                if let Some(code_block) = code_block.as_mut() {
+                    code_block.push_str(text);
+                } else if let Some(answer) = emphasized_code(&tokens[..i], text, &tokens[i + 1..]) {
+                    description.answer = Some(answer);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    descriptions
+}
+
+/// The text when it is wrapped as `<code><em>text</em></code>`.
+fn emphasized_code(before: &[Token], text: &str, after: &[Token]) -> Option<String> {
+    let opened = before.len() >= 2
+        && before[before.len() - 2].is_start("code")
+        && before[before.len() - 1].is_start("em");
+    let closed = after.len() >= 2 && after[0].is_end("em") && after[1].is_end("code");
+
+    (opened && closed).then(|| text.trim().to_string())
+}
+
+/// Picks one of the candidate blocks, asking which one when there are several.
+///
+/// Returns the index of the chosen block, or `None` when there are no candidates or the prompt
+/// is skipped with an empty line.
+pub fn choose_block(
+    part: &str,
+    candidates: &[String],
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<Option<usize>> {
+    match candidates.len() {
+        0 => return Ok(None),
+        1 => return Ok(Some(0)),
+        _ => {}
+    }
+
+    writeln!(output, "Part {} has several code blocks:\n", part)?;
+    for (i, candidate) in candidates.iter().enumerate() {
+        writeln!(output, "[{}]", i + 1)?;
+        for line in candidate.lines().take(5) {
+            writeln!(output, "    {}", line)?;
+        }
+        if candidate.lines().count() > 5 {
+            writeln!(output, "    ...")?;
+        }
+    }
+
+    loop {
+        write!(
+            output,
+            "\nWhich one is the example? [1-{}, empty to skip] ",
+            candidates.len()
+        )?;
+        output.flush()?;
+
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            bail!("No block chosen for part {}", part);
+        }
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return Ok(None);
+        }
+        match answer.parse::<usize>() {
+            Ok(choice) if (1..=candidates.len()).contains(&choice) => return Ok(Some(choice - 1)),
+            _ => writeln!(output, "{} is not one of the blocks", answer)?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const PUZZLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   <em>5</em>
+</code></pre>
+<p>The smallest number in the left list is <code>1</code>.</p>
+<p>In the example above, this is <code>2 + 1 + 0 + 1 + 2 + 5</code>, a total distance of <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>So, for these example lists, the similarity score at the end of this process is <code><em>31</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_descriptions() {
+        let descriptions = parse_descriptions(PUZZLE);
+
+        assert_eq!(
+            descriptions,
+            vec![
+                PartDescription {
+                    code_blocks: vec!["3   4\n4   3\n2   5\n".to_string()],
+                    answer: Some("11".to_string()),
+                },
+                PartDescription {
+                    code_blocks: vec![],
+                    answer: Some("31".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_descriptions_without_puzzle() {
+        assert_eq!(parse_descriptions("<p>Not found</p>"), vec![]);
+    }
+
+    #[test]
+    fn test_choose_block() {
+        let candidates = vec!["a\n".to_string(), "b\n".to_string()];
+        let mut output = vec![];
+
+        let choice = choose_block("one", &candidates, &mut "3\n2\n".as_bytes(), &mut output);
+
+        assert_eq!(choice.unwrap(), Some(1));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("[2]\n    b\n"));
+        assert!(output.contains("3 is not one of the blocks"));
+    }
+
+    #[test]
+    fn test_choose_single_block() {
+        let candidates = vec!["a\n".to_string()];
+
+        let choice = choose_block("one", &candidates, &mut "".as_bytes(), &mut vec![]);
+
+        assert_eq!(choice.unwrap(), Some(0));
+    }
+}
@@ -0,0 +1,271 @@
+//! The summary of a run over every day of a year, gathered from the records each day printed.
+
+use std::time::Duration;
+
+use aocr::runner::{format_duration, table::markdown_table, Part, Record, Status as RecordStatus};
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Summary {
+    pub year: u16,
+    pub days: Vec<DaySummary>,
+    /// The time spent in every solution, in nanoseconds.
+    pub total_ns: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DaySummary {
+    pub day: u8,
+    /// Whether the day failed to run, in which case no parts are reported.
+    pub failed: bool,
+    pub parts: Vec<PartSummary>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PartSummary {
+    pub part: Part,
+    pub answer: String,
+    pub time_ns: u64,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Correct,
+    Incorrect,
+    Unverified,
+}
+
+impl Summary {
+    pub fn new(year: u16, days: Vec<DaySummary>) -> Self {
+        let total_ns = days
+            .iter()
+            .flat_map(|day| &day.parts)
+            .map(|part| part.time_ns)
+            .sum();
+
+        Summary {
+            year,
+            days,
+            total_ns,
+        }
+    }
+
+    /// Renders the summary as a Markdown table with a row per day and the total time last.
+    pub fn markdown(&self) -> String {
+        let mut rows = self
+            .days
+            .iter()
+            .map(|day| {
+                let cell = |part: Part| match day.failed {
+                    true => "failed".to_string(),
+                    false => day
+                        .parts
+                        .iter()
+                        .find(|summary| summary.part == part)
+                        .map(PartSummary::cell)
+                        .unwrap_or_default(),
+                };
+                let time = day.parts.iter().map(|part| part.time_ns).sum::<u64>();
+
+                vec![
+                    format!("{:02}", day.day),
+                    cell(Part::One),
+                    cell(Part::Two),
+                    match day.failed {
+                        true => String::new(),
+                        false => format_duration(Duration::from_nanos(time)),
+                    },
+                ]
+            })
+            .collect::<Vec<_>>();
+        rows.push(vec![
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            format_duration(Duration::from_nanos(self.total_ns)),
+        ]);
+
+        markdown_table(&["Day", "Part one", "Part two", "Time"], &rows)
+    }
+}
+
+impl DaySummary {
+    /// Summarizes the given parts from the records the day's run printed, the first one of each
+    /// part. The day counts as failed when it printed none, or when any of its solutions failed.
+    pub fn from_records(day: u8, records: &[Record], parts: &[Part]) -> Self {
+        let failed = records.is_empty()
+            || records
+                .iter()
+                .any(|record| record.status == RecordStatus::Failed);
+        if failed {
+            return DaySummary {
+                day,
+                failed: true,
+                parts: vec![],
+            };
+        }
+
+        let parts = parts
+            .iter()
+            .filter_map(|part| {
+                let record = records.iter().find(|record| record.part == *part)?;
+                let status = match record.status {
+                    RecordStatus::Correct => Status::Correct,
+                    RecordStatus::Incorrect => Status::Incorrect,
+                    _ => Status::Unverified,
+                };
+
+                Some(PartSummary {
+                    part: part.clone(),
+                    answer: record.answer.clone().unwrap_or_default(),
+                    time_ns: record.solve_time_ns.unwrap_or_default(),
+                    status,
+                    expected: record.expected.clone(),
+                })
+            })
+            .collect();
+
+        DaySummary {
+            day,
+            failed: false,
+            parts,
+        }
+    }
+}
+
+impl PartSummary {
+    fn cell(&self) -> String {
+        match (&self.status, &self.expected) {
+            (Status::Correct, _) => format!("{} ✅", self.answer),
+            (Status::Incorrect, Some(expected)) => {
+                format!("{} ❌ (expected {})", self.answer, expected)
+            }
+            _ => self.answer.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn part(part: Part, answer: &str, time_ns: u64, status: Status) -> PartSummary {
+        PartSummary {
+            part,
+            answer: answer.to_string(),
+            time_ns,
+            status,
+            expected: None,
+        }
+    }
+
+    fn record(part: Part, answer: Option<&str>, status: RecordStatus) -> Record {
+        Record {
+            year: Some(2024),
+            day: Some(1),
+            part,
+            name: "solution".to_string(),
+            profile: None,
+            example: None,
+            answer: answer.map(ToString::to_string),
+            status,
+            expected: None,
+            parse_time_ns: None,
+            solve_time_ns: answer.map(|_| 5_000),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_from_records() {
+        let parts = [Part::One, Part::Two];
+        assert!(DaySummary::from_records(1, &[], &parts).failed);
+
+        let records = [
+            Record {
+                expected: Some("12".to_string()),
+                ..record(Part::One, Some("11"), RecordStatus::Incorrect)
+            },
+            record(Part::Two, Some("31"), RecordStatus::Unverified),
+        ];
+        let summary = DaySummary::from_records(1, &records, &parts);
+
+        assert_eq!(
+            summary,
+            DaySummary {
+                day: 1,
+                failed: false,
+                parts: vec![
+                    PartSummary {
+                        expected: Some("12".to_string()),
+                        ..part(Part::One, "11", 5_000, Status::Incorrect)
+                    },
+                    part(Part::Two, "31", 5_000, Status::Unverified),
+                ],
+            }
+        );
+
+        let only_two = DaySummary::from_records(1, &records, &[Part::Two]);
+        assert_eq!(only_two.parts.len(), 1);
+
+        let records = [
+            record(Part::One, Some("11"), RecordStatus::Correct),
+            record(Part::Two, None, RecordStatus::Failed),
+        ];
+        assert!(DaySummary::from_records(1, &records, &parts).failed);
+    }
+
+    #[test]
+    fn test_markdown() {
+        let summary = Summary::new(
+            2024,
+            vec![
+                DaySummary {
+                    day: 1,
+                    failed: false,
+                    parts: vec![
+                        part(Part::One, "11", 5_000, Status::Correct),
+                        part(Part::Two, "31", 7_000, Status::Unverified),
+                    ],
+                },
+                DaySummary {
+                    day: 2,
+                    failed: true,
+                    parts: vec![],
+                },
+            ],
+        );
+
+        let expected = "\
+| Day   | Part one | Part two |     Time |
+| :---- | -------: | -------: | -------: |
+| 01    |     11 ✅ |       31 | 12.00 µs |
+| 02    |   failed |   failed |          |
+| Total |          |          | 12.00 µs |
+";
+        assert_eq!(summary.total_ns, 12_000);
+        assert_eq!(summary.markdown(), expected);
+    }
+
+    #[test]
+    fn test_json() {
+        let summary = Summary::new(
+            2024,
+            vec![DaySummary {
+                day: 1,
+                failed: false,
+                parts: vec![part(Part::One, "11", 5_000, Status::Correct)],
+            }],
+        );
+
+        assert_eq!(
+            serde_json::to_string(&summary).unwrap(),
+            r#"{"year":2024,"days":[{"day":1,"failed":false,"parts":[{"part":"one","answer":"11","time_ns":5000,"status":"correct"}]}],"total_ns":5000}"#
+        );
+    }
+}
@@ -1,80 +1,738 @@
-use aocr::runner::Part;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use aocr::{examples::Example, runner::Part};
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{parse::Parse, parse_macro_input, Ident, ItemFn, LitStr, Token};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Expr, ExprLit, FnArg, Ident, ItemFn, Lit, LitStr, ReturnType, Token, Type,
+};
 
 struct AocArgs {
-    part: Option<LitStr>,
-    name: Option<LitStr>,
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Part,
+    name: String,
 }
 
-impl From<AocArgs> for Aoc {
-    fn from(args: AocArgs) -> Self {
-        let part = args
-            .part
-            .map_or_else(|| "one".to_string(), |lit_str| lit_str.value())
-            .into();
+struct GeneratorArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+}
 
-        let name = args
-            .name
-            .map_or_else(|| "solution".to_string(), |lit_str| lit_str.value());
+impl Parse for AocArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = AocArgs {
+            year: None,
+            day: None,
+            part: Part::One,
+            name: "solution".to_string(),
+        };
 
-        Aoc { part, name }
+        for (key, value) in parse_pairs(input, "aoc", &["year", "day", "part", "name"])? {
+            match key.to_string().as_str() {
+                "year" => args.year = Some(year_value(&value)?),
+                "day" => args.day = Some(day_value(&value)?),
+                "part" => args.part = part_value(&value)?,
+                _ => args.name = name_value(&value)?,
+            }
+        }
+
+        Ok(args)
     }
 }
 
-struct Aoc {
-    part: Part,
-    name: String,
-}
+impl Parse for GeneratorArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = GeneratorArgs {
+            year: None,
+            day: None,
+        };
 
-impl Parse for AocArgs {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let mut part: Option<LitStr> = None;
-        let mut name: Option<LitStr> = None;
+        for (key, value) in parse_pairs(input, "aoc_generator", &["year", "day"])? {
+            match key.to_string().as_str() {
+                "year" => args.year = Some(year_value(&value)?),
+                _ => args.day = Some(day_value(&value)?),
+            }
+        }
+
+        Ok(args)
+    }
+}
 
-        while !input.is_empty() {
-            let ident: Ident = input.parse()?;
+/// The `key = literal` pairs given to an attribute, each key being one of the attribute's own.
+fn parse_pairs(
+    input: ParseStream,
+    attribute: &str,
+    keys: &[&str],
+) -> syn::Result<Vec<(Ident, Lit)>> {
+    let mut pairs: Vec<(Ident, Lit)> = vec![];
 
-            input.parse::<Token![=]>()?;
+    while !input.is_empty() {
+        let key: Ident = input.parse()?;
+        if !keys.iter().any(|known| key == known) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!(
+                    "Unknown argument `{}` for #[{}], expected one of {}",
+                    key,
+                    attribute,
+                    keys.join(", ")
+                ),
+            ));
+        }
+        if pairs.iter().any(|(other, _)| *other == key) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("Duplicate argument `{}` for #[{}]", key, attribute),
+            ));
+        }
 
-            if ident == "part" {
-                part = Some(input.parse()?);
-            } else if ident == "name" {
-                name = Some(input.parse()?);
-            } else {
-                return Err(syn::Error::new(ident.span(), "Unknown argument for #[aoc]"));
+        input.parse::<Token![=]>()?;
+        let value = match input.parse::<Expr>()? {
+            Expr::Lit(ExprLit { lit, .. }) => lit,
+            value => {
+                return Err(syn::Error::new_spanned(
+                    value,
+                    format!("Expected a literal value for `{}`", key),
+                ))
             }
+        };
+        pairs.push((key, value));
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(pairs)
+}
+
+fn year_value(value: &Lit) -> syn::Result<u16> {
+    match value {
+        Lit::Int(year) if year.base10_parse::<u16>().is_ok_and(|year| year >= 2015) => {
+            year.base10_parse()
+        }
+        _ => Err(syn::Error::new_spanned(
+            value,
+            "Expected a year from 2015 on, such as year = 2024",
+        )),
+    }
+}
+
+fn day_value(value: &Lit) -> syn::Result<u8> {
+    match value {
+        Lit::Int(day)
+            if day
+                .base10_parse::<u8>()
+                .is_ok_and(|day| (1..=25).contains(&day)) =>
+        {
+            day.base10_parse()
+        }
+        _ => Err(syn::Error::new_spanned(
+            value,
+            "Expected a day from 1 to 25, such as day = 1",
+        )),
+    }
+}
+
+fn part_value(value: &Lit) -> syn::Result<Part> {
+    let part = match value {
+        Lit::Str(part) => part.value(),
+        Lit::Int(part) => part.base10_digits().to_string(),
+        _ => String::new(),
+    };
 
-            if input.peek(Token![,]) {
-                input.parse::<Token![,]>()?;
+    part.parse().map_err(|_| {
+        syn::Error::new_spanned(
+            value,
+            "Expected part one or two, such as part = \"two\" or part = 2",
+        )
+    })
+}
+
+fn name_value(value: &Lit) -> syn::Result<String> {
+    match value {
+        Lit::Str(name)
+            if !name.value().is_empty()
+                && name
+                    .value()
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_') =>
+        {
+            Ok(name.value())
+        }
+        _ => Err(syn::Error::new_spanned(
+            value,
+            "Expected a name made of letters, digits and underscores, such as name = \"nom\"",
+        )),
+    }
+}
+
+/// Checks the function has the shape of a solution or generator: neither async nor generic,
+/// taking a single reference and returning a value. Generators only take `&str`, while solutions
+/// may also take a reference to what the generator returns.
+fn check_signature(input_fn: &ItemFn, attribute: &str, str_only: bool) -> syn::Result<()> {
+    let sig = &input_fn.sig;
+    if let Some(asyncness) = sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            format!("#[{}] functions can't be async", attribute),
+        ));
+    }
+    if !sig.generics.params.is_empty() || sig.generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            format!("#[{}] functions can't be generic", attribute),
+        ));
+    }
+
+    let expected = match str_only {
+        true => "`&str`",
+        false => "`&str`, or a reference to what the generator returns",
+    };
+    let mut inputs = sig.inputs.iter();
+    match (inputs.next(), inputs.next()) {
+        (Some(FnArg::Typed(arg)), None) => match arg.ty.as_ref() {
+            Type::Reference(reference)
+                if reference.mutability.is_none() && (!str_only || is_str(&reference.elem)) =>
+            {
+                check_referent(&reference.elem, attribute)?
+            }
+            ty => {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    format!("#[{}] functions take the input as {}", attribute, expected),
+                ))
             }
+        },
+        (Some(FnArg::Receiver(receiver)), _) => {
+            return Err(syn::Error::new_spanned(
+                receiver,
+                format!("#[{}] functions can't take self", attribute),
+            ))
         }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &sig.inputs,
+                format!(
+                    "#[{}] functions take a single parameter, the input as {}",
+                    attribute, expected
+                ),
+            ))
+        }
+    }
+
+    if let ReturnType::Default = sig.output {
+        return Err(syn::Error::new_spanned(
+            &sig.ident,
+            format!("#[{}] functions should return a value", attribute),
+        ));
+    }
+
+    Ok(())
+}
 
-        Ok(AocArgs { part, name })
+/// Checks the input is taken as a reference to a sized type, besides `str`, as the generator
+/// returns the value it refers to.
+fn check_referent(ty: &Type, attribute: &str) -> syn::Result<()> {
+    match ty {
+        Type::ImplTrait(_) => Err(syn::Error::new_spanned(
+            ty,
+            format!("#[{}] functions can't be generic", attribute),
+        )),
+        Type::Slice(_) | Type::TraitObject(_) => Err(syn::Error::new_spanned(
+            ty,
+            format!(
+                "#[{}] functions can't take an unsized input other than `&str`, take a `&Vec<T>` \
+                 or `&String` returned by the generator instead",
+                attribute
+            ),
+        )),
+        _ => Ok(()),
     }
 }
 
+fn is_str(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("str"))
+}
+
+/// The year or day of a registered function, as given to the attribute or `None` to infer it.
+fn option_tokens(value: Option<impl quote::ToTokens>) -> TokenStream2 {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+/// Registers a solution for a part, under a name that defaults to `solution`.
+///
+/// The part is given as `part = "two"` or `part = 2` and defaults to one. As with
+/// `#[aoc_generator]`, `day` and `year` tie the solution to a puzzle when that can't be inferred.
+/// The function takes the input as `&str`, or a reference to what the generator returns.
+///
+/// Each part and name may only be registered once per module, so a duplicate fails to compile
+/// with an error pointing at both functions. The macro only sees the module it expands in, so
+/// duplicates defined in different modules of the same day still compile, and are only reported
+/// when the runner collects the functions.
+///
+/// ```compile_fail,E0428
+/// use aocr_macro::aoc;
+///
+/// #[aoc(part = "one")]
+/// fn closures(input: &str) -> usize {
+///     input.len()
+/// }
+///
+/// #[aoc(part = "one")]
+/// fn for_loop(input: &str) -> usize {
+///     input.len()
+/// }
+/// ```
+///
+/// Solutions of different puzzles may share a module, as long as the attribute tells them apart:
+///
+/// ```
+/// use aocr_macro::aoc;
+///
+/// #[aoc(day = 1)]
+/// fn day_one(input: &str) -> usize {
+///     input.len()
+/// }
+///
+/// #[aoc(day = 2)]
+/// fn day_two(input: &str) -> usize {
+///     input.len()
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Names differing only in case are different names, to the runner as well:
+///
+/// ```
+/// use aocr_macro::aoc;
+///
+/// #[aoc(name = "nom")]
+/// fn nom(input: &str) -> usize {
+///     input.len()
+/// }
+///
+/// #[aoc(name = "NOM")]
+/// fn nom_shouting(input: &str) -> usize {
+///     input.len()
+/// }
+/// # fn main() {}
+/// ```
 #[proc_macro_attribute]
 pub fn aoc(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as AocArgs);
     let input_fn = parse_macro_input!(input as ItemFn);
-
-    let args: Aoc = args.into();
+    if let Err(error) = check_signature(&input_fn, "aoc", false) {
+        return error.to_compile_error().into();
+    }
 
     let fn_name = &input_fn.sig.ident;
+    let (year, day) = (option_tokens(args.year), option_tokens(args.day));
     let (part, name) = (args.part.as_str(), args.name);
+    let part_variant = match args.part {
+        Part::One => quote! { One },
+        Part::Two => quote! { Two },
+    };
+
+    let solution_fn_name = format_ident!("__aocr_solution_{}", fn_name);
+    let solution_static_name =
+        format_ident!("__AOCR_SOLUTION_{}", fn_name.to_string().to_uppercase());
+    // Named after the puzzle given to the attribute, the part and the name, keeping the case of
+    // the name as the runner does, and spanned at the function, so registering them all twice in
+    // a module fails to compile with an error pointing at each of the functions.
+    let registration_name = format_ident!(
+        "__AOCR_Y{}_D{}_PART_{}_{}",
+        args.year.map(|year| year.to_string()).unwrap_or_default(),
+        args.day.map(|day| day.to_string()).unwrap_or_default(),
+        part.to_uppercase(),
+        name
+    );
+    let registration = quote_spanned! {fn_name.span()=>
+        #[doc(hidden)]
+        #[allow(dead_code, non_upper_case_globals)]
+        const #registration_name: () = ();
+    };
+
+    let doc = doc_comment(&input_fn);
+    let line = quote_spanned! {fn_name.span()=> line!() };
+
+    let argument = match parsed_input_type(&input_fn) {
+        Some(parsed_type) => quote! { input.parsed::<#parsed_type>()? },
+        None => quote! { input.raw() },
+    };
+    let call = convert_output(
+        &input_fn,
+        quote! { #fn_name(#argument) },
+        quote! { aocr::runner::Answer::new },
+    );
+
+    let gen = quote! {
+        #input_fn
+
+        fn #solution_fn_name(input: &aocr::runner::Input) -> aocr::runner::__private::miette::Result<aocr::runner::Answer> {
+            #call
+        }
+
+        #registration
 
-    let register_fn_name = format_ident!("register_function_{}_{}", part, name);
+        #[aocr::runner::__private::linkme::distributed_slice(aocr::runner::SOLUTIONS)]
+        #[linkme(crate = aocr::runner::__private::linkme)]
+        static #solution_static_name: aocr::runner::Solution = aocr::runner::Solution {
+            year: #year,
+            day: #day,
+            package: env!("CARGO_PKG_NAME"),
+            bin: option_env!("CARGO_BIN_NAME"),
+            module: module_path!(),
+            file: file!(),
+            line: #line,
+            doc: #doc,
+            part: aocr::runner::Part::#part_variant,
+            name: #name,
+            func: #solution_fn_name,
+        };
+    };
+
+    TokenStream::from(gen)
+}
+
+/// Registers a generator that parses the puzzle input once for all parts.
+///
+/// The generator takes the raw input as `&str` and returns any `'static` value, or a `Result` of
+/// one. Solutions receive the generated value by reference by taking `&T` instead of `&str`.
+/// In a binary holding several days, `day = N` ties the generator to a day when it isn't defined
+/// in a `dayNN` module, and `year = N` to a year when the package isn't named after it.
+#[proc_macro_attribute]
+pub fn aoc_generator(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as GeneratorArgs);
+    let input_fn = parse_macro_input!(input as ItemFn);
+    if let Err(error) = check_signature(&input_fn, "aoc_generator", true) {
+        return error.to_compile_error().into();
+    }
+
+    let (year, day) = (option_tokens(args.year), option_tokens(args.day));
+    let fn_name = &input_fn.sig.ident;
+    let generator_fn_name = format_ident!("__aocr_generator_{}", fn_name);
+    let generator_static_name =
+        format_ident!("__AOCR_GENERATOR_{}", fn_name.to_string().to_uppercase());
+
+    let call = convert_output(
+        &input_fn,
+        quote! { #fn_name(input) },
+        quote! { |parsed| Box::new(parsed) as Box<dyn std::any::Any> },
+    );
 
     let gen = quote! {
         #input_fn
 
-        #[ctor::ctor]
-        fn #register_fn_name() {
-            aocr::runner::register_function(#part, #name, #fn_name);
+        fn #generator_fn_name(input: &str) -> aocr::runner::__private::miette::Result<Box<dyn std::any::Any>> {
+            #call
+        }
+
+        #[aocr::runner::__private::linkme::distributed_slice(aocr::runner::GENERATORS)]
+        #[linkme(crate = aocr::runner::__private::linkme)]
+        static #generator_static_name: aocr::runner::Generator = aocr::runner::Generator {
+            year: #year,
+            day: #day,
+            package: env!("CARGO_PKG_NAME"),
+            bin: option_env!("CARGO_BIN_NAME"),
+            module: module_path!(),
+            func: #generator_fn_name,
+        };
+    };
+
+    TokenStream::from(gen)
+}
+
+/// Generates a test for every example of the given input and every part it has an answer for.
+///
+/// The input path is relative to the workspace root, as in the day's `main`, and each test checks
+/// every function registered for the part. Examples are picked up when the crate is compiled, so
+/// adding one needs a rebuild, while editing an existing one is tracked by cargo.
+///
+/// ```ignore
+/// aocr_macro::aoc_examples!("inputs/2024/01.txt");
+/// ```
+#[proc_macro]
+pub fn aoc_examples(input: TokenStream) -> TokenStream {
+    let input_path = parse_macro_input!(input as LitStr);
+
+    let day = Path::new(&input_path.value())
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.parse::<u8>().ok());
+    let Some(day) = day else {
+        return syn::Error::new(
+            input_path.span(),
+            "The input file should be named after its day, such as 01.txt",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let paths = match Example::find(&workspace_path(&input_path.value())) {
+        Ok(paths) => paths,
+        Err(report) => {
+            return syn::Error::new(input_path.span(), report)
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut tests = vec![];
+    for path in paths {
+        let example = match Example::load(&path) {
+            Ok(example) => example,
+            Err(report) => {
+                return syn::Error::new(input_path.span(), report)
+                    .to_compile_error()
+                    .into()
+            }
+        };
+
+        let path = path.display().to_string();
+        for (part, variant) in [(Part::One, quote! { One }), (Part::Two, quote! { Two })] {
+            if example.expected(&part).is_none() {
+                continue;
+            }
+
+            let test_name = format_ident!("{}_part_{}", example.name(), part.as_str());
+            tests.push(quote! {
+                #[test]
+                fn #test_name() {
+                    let part = aocr::runner::Part::#variant;
+                    if let Err(report) =
+                        aocr::runner::check_example(#path, include_str!(#path), #day, &part)
+                    {
+                        panic!("{:?}", report);
+                    }
+                }
+            });
+        }
+    }
+
+    let gen = quote! {
+        #[cfg(test)]
+        mod aoc_examples {
+            #(#tests)*
         }
     };
 
     TokenStream::from(gen)
 }
+
+/// The doc comment of the function, one line per `///` line without the space after it.
+fn doc_comment(input_fn: &ItemFn) -> String {
+    input_fn
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) => {
+                let line = doc.value();
+                Some(line.strip_prefix(' ').unwrap_or(&line).to_string())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Resolves a path relative to the workspace root, which holds the `inputs` directory.
+///
+/// Tests run from the crate's directory rather than the workspace root, so the path is looked up
+/// from the crate upwards.
+fn workspace_path(path: &str) -> PathBuf {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+
+    manifest_dir
+        .ancestors()
+        .map(|dir| dir.join(path))
+        .find(|path| path.parent().is_some_and(Path::is_dir))
+        .unwrap_or_else(|| manifest_dir.join(path))
+}
+
+/// Wraps `call` so it evaluates to a `miette::Result`, mapping successful values through `map`.
+fn convert_output(input_fn: &ItemFn, call: TokenStream2, map: TokenStream2) -> TokenStream2 {
+    if returns_result(input_fn) {
+        quote! {
+            match #call {
+                Ok(output) => Ok((#map)(output)),
+                Err(error) => {
+                    #[allow(unused_imports)]
+                    use aocr::runner::__private::{BoxedKindTrait, DiagnosticKindTrait, ReportKindTrait};
+                    Err((&error).aocr_error_kind().into_report(error))
+                }
+            }
+        }
+    } else {
+        quote! { Ok((#map)(#call)) }
+    }
+}
+
+/// Whether the function returns some flavour of `Result` (`std`, `anyhow`, `miette`, ...).
+fn returns_result(input_fn: &ItemFn) -> bool {
+    match &input_fn.sig.output {
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
+
+/// The generated type a solution takes by reference, or `None` when it takes the raw `&str`.
+fn parsed_input_type(input_fn: &ItemFn) -> Option<&Type> {
+    match input_fn.sig.inputs.first() {
+        Some(FnArg::Typed(arg)) => match arg.ty.as_ref() {
+            Type::Reference(reference) => match reference.elem.as_ref() {
+                elem if is_str(elem) => None,
+                parsed_type => Some(parsed_type),
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    fn aoc_args(tokens: TokenStream2) -> syn::Result<AocArgs> {
+        syn::parse2(tokens)
+    }
+
+    fn error(result: syn::Result<impl Sized>) -> String {
+        result
+            .err()
+            .map(|error| error.to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_aoc_args() {
+        let args = aoc_args(quote! { part = 2, name = "nom", day = 3, year = 2024 }).unwrap();
+        assert_eq!(
+            (args.part, args.name, args.day, args.year),
+            (Part::Two, "nom".to_string(), Some(3), Some(2024))
+        );
+
+        let args = aoc_args(quote! { part = "Two" }).unwrap();
+        assert_eq!((args.part, args.name), (Part::Two, "solution".to_string()));
+
+        let args = aoc_args(quote! {}).unwrap();
+        assert_eq!(args.part, Part::One);
+    }
+
+    #[test]
+    fn test_aoc_args_errors() {
+        assert_eq!(
+            error(aoc_args(quote! { part = "Three" })),
+            "Expected part one or two, such as part = \"two\" or part = 2"
+        );
+        assert_eq!(
+            error(aoc_args(quote! { part = 3 })),
+            "Expected part one or two, such as part = \"two\" or part = 2"
+        );
+        assert_eq!(
+            error(aoc_args(quote! { parts = "one" })),
+            "Unknown argument `parts` for #[aoc], expected one of year, day, part, name"
+        );
+        assert_eq!(
+            error(aoc_args(quote! { part = PART })),
+            "Expected a literal value for `part`"
+        );
+        assert_eq!(
+            error(aoc_args(quote! { part = 1, part = 2 })),
+            "Duplicate argument `part` for #[aoc]"
+        );
+        assert_eq!(
+            error(aoc_args(quote! { name = "my-solution" })),
+            "Expected a name made of letters, digits and underscores, such as name = \"nom\""
+        );
+        assert_eq!(
+            error(aoc_args(quote! { day = 26 })),
+            "Expected a day from 1 to 25, such as day = 1"
+        );
+        assert_eq!(
+            error(syn::parse2::<GeneratorArgs>(quote! { part = 1 })),
+            "Unknown argument `part` for #[aoc_generator], expected one of year, day"
+        );
+    }
+
+    #[test]
+    fn test_check_signature() {
+        let solution: ItemFn = parse_quote! { fn solve(input: &Vec<u32>) -> u32 { 0 } };
+        assert!(check_signature(&solution, "aoc", false).is_ok());
+
+        let cases: [(ItemFn, &str); 9] = [
+            (
+                parse_quote! { async fn solve(input: &str) -> u32 { 0 } },
+                "#[aoc] functions can't be async",
+            ),
+            (
+                parse_quote! { fn solve<T>(input: &T) -> u32 { 0 } },
+                "#[aoc] functions can't be generic",
+            ),
+            (
+                parse_quote! { fn solve(input: String) -> u32 { 0 } },
+                "#[aoc] functions take the input as `&str`, or a reference to what the generator returns",
+            ),
+            (
+                parse_quote! { fn solve(input: &mut Vec<u32>) -> u32 { 0 } },
+                "#[aoc] functions take the input as `&str`, or a reference to what the generator returns",
+            ),
+            (
+                parse_quote! { fn solve(input: &[u8]) -> u32 { 0 } },
+                "#[aoc] functions can't take an unsized input other than `&str`, take a `&Vec<T>` or `&String` returned by the generator instead",
+            ),
+            (
+                parse_quote! { fn solve(input: &dyn Display) -> u32 { 0 } },
+                "#[aoc] functions can't take an unsized input other than `&str`, take a `&Vec<T>` or `&String` returned by the generator instead",
+            ),
+            (
+                parse_quote! { fn solve(input: &impl Display) -> u32 { 0 } },
+                "#[aoc] functions can't be generic",
+            ),
+            (
+                parse_quote! { fn solve(input: &str, extra: u32) -> u32 { 0 } },
+                "#[aoc] functions take a single parameter, the input as `&str`, or a reference to what the generator returns",
+            ),
+            (
+                parse_quote! { fn solve(input: &str) {} },
+                "#[aoc] functions should return a value",
+            ),
+        ];
+        for (input_fn, expected) in cases {
+            assert_eq!(error(check_signature(&input_fn, "aoc", false)), expected);
+        }
+
+        let generator: ItemFn = parse_quote! { fn parse(input: &[u8]) -> u32 { 0 } };
+        assert_eq!(
+            error(check_signature(&generator, "aoc_generator", true)),
+            "#[aoc_generator] functions take the input as `&str`"
+        );
+    }
+}
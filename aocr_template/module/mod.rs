@@ -0,0 +1,3 @@
+mod solutions;
+
+aocr_macro::aoc_examples!("inputs/{{year}}/{{day}}.txt");